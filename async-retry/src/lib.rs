@@ -9,4 +9,6 @@ pub mod retry_with_timeout;
 
 pub use error::Error;
 pub use retry::{Retry, retry};
-pub use retry_with_timeout::{retry_with_timeout, retry_with_timeout_for_non_logic_error};
+pub use retry_with_timeout::{
+    retry_with_policy_timeout, retry_with_timeout, retry_with_timeout_for_non_logic_error,
+};
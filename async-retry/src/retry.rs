@@ -6,18 +6,20 @@ use core::{
     ops::ControlFlow,
     pin::Pin,
     task::{Context, Poll},
+    time::Duration,
 };
 
 use async_sleep::{Sleepble, sleep};
 use futures_util::{FutureExt as _, future::FusedFuture};
 use pin_project_lite::pin_project;
-use retry_policy::RetryPolicy;
+use retry_policy::{RetryPolicy, retry_backoff::BackoffState};
 
 use crate::error::Error;
 
 //
+/// called with [`RetryPolicy::attempt_timeout`] for the attempt
 type RetryFutureRepeater<T, E> =
-    Box<dyn FnMut() -> Pin<Box<dyn Future<Output = Result<T, E>> + Send>> + Send>;
+    Box<dyn FnMut(Option<Duration>) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send>> + Send>;
 
 //
 pin_project! {
@@ -27,6 +29,8 @@ pin_project! {
         //
         state: State<T, E>,
         attempts: usize,
+        attempt_clock: AttemptClock,
+        backoff_state: BackoffState,
         errors: Option<Vec<E>>,
         //
         phantom: PhantomData<(SLEEP, T, E)>,
@@ -53,6 +57,8 @@ impl<SLEEP, POL, T, E> Retry<SLEEP, POL, T, E> {
             //
             state: State::Pending,
             attempts: 0,
+            attempt_clock: AttemptClock::default(),
+            backoff_state: BackoffState::default(),
             errors: Some(vec![]),
             //
             phantom: PhantomData,
@@ -78,6 +84,36 @@ impl<T, E> fmt::Debug for State<T, E> {
     }
 }
 
+//
+/// Measures each attempt for [`BackoffState::last_attempt_elapsed`], a no-op without std.
+#[derive(Debug, Default)]
+struct AttemptClock {
+    #[cfg(feature = "std")]
+    started_at: Option<std::time::Instant>,
+}
+
+impl AttemptClock {
+    fn start(&mut self) {
+        #[cfg(feature = "std")]
+        {
+            self.started_at = Some(std::time::Instant::now());
+        }
+    }
+
+    fn elapsed(&mut self) -> Option<Duration> {
+        #[cfg(feature = "std")]
+        {
+            self.started_at
+                .take()
+                .map(|started_at| started_at.elapsed())
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            None
+        }
+    }
+}
+
 //
 pub fn retry<SLEEP, POL, F, Fut, T, E>(policy: POL, future_repeater: F) -> Retry<SLEEP, POL, T, E>
 where
@@ -86,7 +122,7 @@ where
     F: Fn() -> Fut + Send + 'static,
     Fut: Future<Output = Result<T, E>> + Send + 'static,
 {
-    Retry::new(policy, Box::new(move || Box::pin(future_repeater())))
+    Retry::new(policy, Box::new(move |_| Box::pin(future_repeater())))
 }
 
 //
@@ -114,7 +150,9 @@ where
         loop {
             match this.state {
                 State::Pending => {
-                    let future = (this.future_repeater)();
+                    let timeout = this.policy.attempt_timeout(*this.attempts + 1);
+                    let future = (this.future_repeater)(timeout);
+                    this.attempt_clock.start();
 
                     //
                     *this.state = State::Fut(future);
@@ -127,6 +165,7 @@ where
                             //
                             *this.state = State::Done;
                             *this.attempts = 0;
+                            *this.backoff_state = BackoffState::default();
                             *this.errors = Some(Vec::new());
 
                             break Poll::Ready(Ok(x));
@@ -136,7 +175,16 @@ where
                             *this.attempts += 1;
 
                             //
-                            let ret = this.policy.next_step(&err, *this.attempts);
+                            if let Some(elapsed) = this.attempt_clock.elapsed() {
+                                this.backoff_state.record_attempt_elapsed(elapsed);
+                            }
+
+                            //
+                            let ret = this.policy.next_step_with_state(
+                                &err,
+                                *this.attempts,
+                                this.backoff_state,
+                            );
 
                             //
                             if let Some(errors) = this.errors.as_mut() {
@@ -158,6 +206,7 @@ where
                                     //
                                     *this.state = State::Done;
                                     *this.attempts = 0;
+                                    *this.backoff_state = BackoffState::default();
                                     *this.errors = Some(Vec::new());
 
                                     break Poll::Ready(Err(Error::new(stop_reason, errors)));
@@ -201,8 +250,14 @@ mod tests {
     use retry_policy::{
         StopReason,
         policies::SimplePolicy,
-        retry_backoff::backoffs::FnBackoff,
-        retry_predicate::predicates::{AlwaysPredicate, FnPredicate},
+        retry_backoff::{
+            RetryBackoff,
+            backoffs::{FnBackoff, LatencyAwareBackoff},
+        },
+        retry_predicate::{
+            RetryPredicate,
+            predicates::{AlwaysPredicate, FnPredicate},
+        },
     };
 
     #[tokio::test]
@@ -298,4 +353,121 @@ mod tests {
         let elapsed_dur = now.elapsed();
         assert!(elapsed_dur.as_millis() >= 200 && elapsed_dur.as_millis() <= 205);
     }
+
+    #[tokio::test]
+    async fn test_retry_with_next_step_only_policy() {
+        #[derive(Debug, PartialEq)]
+        struct FError(usize);
+        async fn f(n: usize) -> Result<(), FError> {
+            Err(FError(n))
+        }
+
+        // overrides only next_step, as policies written before BackoffState
+        struct NextStepPolicy;
+        impl RetryPolicy<FError> for NextStepPolicy {
+            fn predicate(&self) -> &dyn RetryPredicate<FError> {
+                unreachable!()
+            }
+            fn max_retries(&self) -> usize {
+                unreachable!()
+            }
+            fn backoff(&self) -> &dyn RetryBackoff {
+                unreachable!()
+            }
+
+            fn next_step(
+                &self,
+                _params: &FError,
+                attempts: usize,
+            ) -> ControlFlow<StopReason, Duration> {
+                if attempts > 2 {
+                    ControlFlow::Break(StopReason::MaxRetriesReached)
+                } else {
+                    ControlFlow::Continue(Duration::from_millis(10))
+                }
+            }
+        }
+
+        match retry::<Sleep, _, _, _, _, _>(NextStepPolicy, || f(0)).await {
+            Ok(_) => panic!(""),
+            Err(err) => {
+                assert_eq!(&err.stop_reason, &StopReason::MaxRetriesReached);
+                assert_eq!(err.errors(), &[FError(0), FError(0), FError(0)]);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_backoff_state() {
+        #[derive(Debug, PartialEq)]
+        struct FError(usize);
+        async fn f(n: usize) -> Result<(), FError> {
+            Err(FError(n))
+        }
+
+        // doubles the previous delay
+        struct DoublingBackoff;
+        impl RetryBackoff for DoublingBackoff {
+            fn delay(&self, _attempts: usize) -> Duration {
+                Duration::from_millis(50)
+            }
+
+            fn delay_with_state(&self, attempts: usize, state: &BackoffState) -> Duration {
+                match state.prev_delay {
+                    Some(prev_delay) => prev_delay * 2,
+                    None => self.delay(attempts),
+                }
+            }
+        }
+
+        //
+        let policy = SimplePolicy::new(AlwaysPredicate, 3, DoublingBackoff);
+
+        //
+        let now = std::time::Instant::now();
+
+        match retry::<Sleep, _, _, _, _, _>(policy, || f(0)).await {
+            Ok(_) => panic!(""),
+            Err(err) => {
+                assert_eq!(&err.stop_reason, &StopReason::MaxRetriesReached);
+                assert_eq!(err.errors().len(), 4);
+            }
+        }
+
+        // 50 + 100 + 200
+        let elapsed_dur = now.elapsed();
+        assert!(elapsed_dur.as_millis() >= 350 && elapsed_dur.as_millis() <= 360);
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_latency_aware_backoff() {
+        #[derive(Debug, PartialEq)]
+        struct FError(usize);
+        async fn f(n: usize) -> Result<(), FError> {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            Err(FError(n))
+        }
+
+        //
+        let policy = SimplePolicy::new(
+            AlwaysPredicate,
+            3,
+            LatencyAwareBackoff::new(2.0, Duration::from_millis(1), Duration::from_secs(1)),
+        );
+
+        //
+        let now = std::time::Instant::now();
+
+        match retry::<Sleep, _, _, _, _, _>(policy, || f(0)).await {
+            Ok(_) => panic!(""),
+            Err(err) => {
+                assert_eq!(&err.stop_reason, &StopReason::MaxRetriesReached);
+                assert_eq!(err.errors().len(), 4);
+            }
+        }
+
+        // 4 * 50 + 3 * 100
+        let elapsed_dur = now.elapsed();
+        assert!(elapsed_dur.as_millis() >= 500 && elapsed_dur.as_millis() <= 530);
+    }
 }
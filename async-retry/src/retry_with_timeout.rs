@@ -1,5 +1,5 @@
 use alloc::boxed::Box;
-use core::{convert::Infallible, fmt, future::Future, time::Duration};
+use core::{convert::Infallible, fmt, future::Future, pin::Pin, time::Duration};
 
 use async_sleep::{
     Sleepble,
@@ -24,21 +24,54 @@ where
 {
     Retry::<SLEEP, _, _, _>::new(
         policy,
-        Box::new(move || {
-            let fut = future_repeater();
-            Box::pin(
-                timeout::<SLEEP, _>(every_performance_timeout_dur, Box::pin(fut)).map_ok_or_else(
-                    |err| Err(ErrorWrapper::Timeout(err)),
-                    |ret| match ret {
-                        Ok(x) => Ok(x),
-                        Err(err) => Err(ErrorWrapper::Inner(err)),
-                    },
-                ),
+        Box::new(move |_| {
+            with_timeout::<SLEEP, _, _, _>(future_repeater(), every_performance_timeout_dur)
+        }),
+    )
+}
+
+/// Like [`retry_with_timeout`], with the timeout of each attempt from [`RetryPolicy::attempt_timeout`],
+/// e.g. the gRPC connect timeout, default_timeout when the policy has none.
+pub fn retry_with_policy_timeout<SLEEP, POL, F, Fut, T, E>(
+    policy: POL,
+    future_repeater: F,
+    default_timeout: Duration,
+) -> Retry<SLEEP, POL, T, ErrorWrapper<E>>
+where
+    SLEEP: Sleepble + 'static,
+    POL: RetryPolicy<ErrorWrapper<E>>,
+    F: Fn() -> Fut + Send + 'static,
+    Fut: Future<Output = Result<T, E>> + Send + 'static,
+{
+    Retry::<SLEEP, _, _, _>::new(
+        policy,
+        Box::new(move |attempt_timeout: Option<Duration>| {
+            with_timeout::<SLEEP, _, _, _>(
+                future_repeater(),
+                attempt_timeout.unwrap_or(default_timeout),
             )
         }),
     )
 }
 
+#[allow(clippy::type_complexity)]
+fn with_timeout<SLEEP, Fut, T, E>(
+    fut: Fut,
+    dur: Duration,
+) -> Pin<Box<dyn Future<Output = Result<T, ErrorWrapper<E>>> + Send>>
+where
+    SLEEP: Sleepble + 'static,
+    Fut: Future<Output = Result<T, E>> + Send + 'static,
+{
+    Box::pin(timeout::<SLEEP, _>(dur, Box::pin(fut)).map_ok_or_else(
+        |err| Err(ErrorWrapper::Timeout(err)),
+        |ret| match ret {
+            Ok(x) => Ok(x),
+            Err(err) => Err(ErrorWrapper::Inner(err)),
+        },
+    ))
+}
+
 //
 pub fn retry_with_timeout_for_non_logic_error<SLEEP, POL, F, Fut, T>(
     policy: POL,
@@ -53,7 +86,7 @@ where
 {
     Retry::<SLEEP, _, _, _>::new(
         policy,
-        Box::new(move || {
+        Box::new(move |_| {
             let fut = future_repeater();
             Box::pin(
                 timeout::<SLEEP, _>(every_performance_timeout_dur, Box::pin(fut))
@@ -164,7 +197,10 @@ mod tests {
     use retry_policy::{
         StopReason,
         policies::SimplePolicy,
-        retry_backoff::backoffs::FnBackoff,
+        retry_backoff::{
+            backoffs::{FnBackoff, GrpcBackoff},
+            random::SeededRandom,
+        },
         retry_predicate::predicates::{AlwaysPredicate, FnPredicate},
     };
 
@@ -320,6 +356,49 @@ mod tests {
         let elapsed_dur = now.elapsed();
         assert!(elapsed_dur.as_millis() >= 500 && elapsed_dur.as_millis() <= 515);
     }
+
+    #[tokio::test]
+    async fn test_retry_with_grpc_connect_timeout() {
+        // each connect takes 45ms
+        static N: LazyLock<AtomicUsize> = LazyLock::new(|| AtomicUsize::new(0));
+        async fn connect() -> Result<(), ()> {
+            N.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(tokio::time::Duration::from_millis(45)).await;
+            Ok(())
+        }
+
+        // deadlines 10ms, 20ms, 40ms, 80ms, at least 30ms to connect
+        let mut backoff = GrpcBackoff::new(SeededRandom::new(1));
+        backoff.initial_backoff = Duration::from_millis(10);
+        backoff.multiplier = 2.0;
+        backoff.jitter = 0.0;
+        backoff.max_backoff = Duration::from_secs(1);
+        backoff.min_connect_timeout = Duration::from_millis(30);
+
+        let policy = SimplePolicy::new(PredicateWrapper::new(AlwaysPredicate), 5, backoff);
+
+        //
+        let now = std::time::Instant::now();
+
+        match retry_with_policy_timeout::<Sleep, _, _, _, _, _>(
+            policy,
+            connect,
+            Duration::from_millis(1),
+        )
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => panic!("{err:?}"),
+        }
+        assert_eq!(N.load(Ordering::SeqCst), 4);
+
+        // timed out after 30ms, 30ms and 40ms, every deadline passed while connecting, so no sleeps
+        let elapsed_dur = now.elapsed();
+        assert!(
+            elapsed_dur.as_millis() >= 145 && elapsed_dur.as_millis() <= 190,
+            "{elapsed_dur:?}"
+        );
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,258 @@
+//! Backoffs from service configs, e.g. `{"type": "exponential", "initial": "100ms", "multiplier": 2.0, "max": "30s"}`,
+//! or from the compact string form in [`crate::backoff_dsl`], e.g. `exp(100ms, x2, max=30s) + jitter(20%)`.
+//!
+//! Durations are [humantime](https://docs.rs/humantime) strings.
+
+use alloc::{boxed::Box, vec::Vec};
+use core::time::Duration;
+
+use crate::{
+    backoffs::{
+        Cap, ConstantBackoff, DecorrelatedJitterBackoff, ExplicitScheduleBackoff,
+        ExponentialBackoff, FibonacciBackoff, Floor, GoogleCloudWorkflowsBackoff, Jitter,
+        JitterMode, LinearBackoff, Offset, Scale,
+    },
+    random::StdRandom,
+    retry_backoff::RetryBackoff,
+};
+
+//
+/// Backoffs without a variant are built in code: Tiered and Chain, Fn, the `impl_*` adapters,
+/// the ones taking a random source other than `StdRandom`, and the error-aware RetryAfter wrappers.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
+pub enum BackoffConfig {
+    Constant(ConstantBackoff),
+    Linear(LinearBackoff),
+    Fibonacci(FibonacciBackoff),
+    Exponential(ExponentialBackoff),
+    GoogleCloudWorkflows(GoogleCloudWorkflowsBackoff),
+    ExplicitSchedule {
+        #[cfg_attr(feature = "serde", serde(with = "humantime_serde_vec"))]
+        delays: Vec<Duration>,
+    },
+    DecorrelatedJitter {
+        #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+        base: Duration,
+        #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+        cap: Duration,
+    },
+    Jitter {
+        inner: Box<BackoffConfig>,
+        mode: JitterMode,
+    },
+    Cap {
+        inner: Box<BackoffConfig>,
+        #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+        max: Duration,
+    },
+    Floor {
+        inner: Box<BackoffConfig>,
+        #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+        min: Duration,
+    },
+    Scale {
+        inner: Box<BackoffConfig>,
+        factor: f64,
+    },
+    Offset {
+        inner: Box<BackoffConfig>,
+        #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+        offset: Duration,
+    },
+}
+
+impl BackoffConfig {
+    /// randomized backoffs use `StdRandom`
+    pub fn build(&self) -> Box<dyn RetryBackoff + Send + Sync> {
+        match self {
+            Self::Constant(backoff) => Box::new(*backoff),
+            Self::Linear(backoff) => Box::new(*backoff),
+            Self::Fibonacci(backoff) => Box::new(*backoff),
+            Self::Exponential(backoff) => Box::new(*backoff),
+            Self::GoogleCloudWorkflows(backoff) => Box::new(backoff.clone()),
+            Self::ExplicitSchedule { delays } => {
+                Box::new(ExplicitScheduleBackoff::new(delays.clone()))
+            }
+            Self::DecorrelatedJitter { base, cap } => {
+                Box::new(DecorrelatedJitterBackoff::new(*base, *cap, StdRandom))
+            }
+            Self::Jitter { inner, mode } => Box::new(Jitter::new(inner.build(), *mode, StdRandom)),
+            Self::Cap { inner, max } => Box::new(Cap::new(inner.build(), *max)),
+            Self::Floor { inner, min } => Box::new(Floor::new(inner.build(), *min)),
+            Self::Scale { inner, factor } => Box::new(Scale::new(inner.build(), *factor)),
+            Self::Offset { inner, offset } => Box::new(Offset::new(inner.build(), *offset)),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod humantime_serde_vec {
+    use alloc::vec::Vec;
+    use core::time::Duration;
+
+    use humantime_serde::Serde;
+    use serde::{Deserialize as _, Deserializer, Serializer};
+
+    pub(super) fn serialize<S>(delays: &[Duration], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(delays.iter().map(|delay| Serde::from(*delay)))
+    }
+
+    pub(super) fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Vec::<Serde<Duration>>::deserialize(deserializer)?
+            .into_iter()
+            .map(Serde::into_inner)
+            .collect())
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_and_build() {
+        for (json, config, name, delays) in [
+            (
+                r#"{"type":"constant","delay":"1s"}"#,
+                BackoffConfig::Constant(ConstantBackoff::new(Duration::from_secs(1))),
+                "Constant",
+                [1000, 1000, 1000],
+            ),
+            (
+                r#"{"type":"linear","initial":"100ms","step":"100ms","max":"250ms"}"#,
+                BackoffConfig::Linear(LinearBackoff::new(
+                    Duration::from_millis(100),
+                    Duration::from_millis(100),
+                    Duration::from_millis(250),
+                )),
+                "Linear",
+                [100, 200, 250],
+            ),
+            (
+                r#"{"type":"fibonacci","initial":"100ms","max":"1m"}"#,
+                BackoffConfig::Fibonacci(FibonacciBackoff::new(
+                    Duration::from_millis(100),
+                    Duration::from_secs(60),
+                )),
+                "Fibonacci",
+                [100, 100, 200],
+            ),
+            (
+                r#"{"type":"exponential","initial":"100ms","multiplier":2.0,"max":"30s"}"#,
+                BackoffConfig::Exponential(ExponentialBackoff::new(
+                    Duration::from_millis(100),
+                    2,
+                    Duration::from_secs(30),
+                )),
+                "Exponential",
+                [100, 200, 400],
+            ),
+            (
+                r#"{"type":"google_cloud_workflows","initial_delay_secs":1.0,"max_delay_secs":60.0,"multiplier":2.0}"#,
+                BackoffConfig::GoogleCloudWorkflows(GoogleCloudWorkflowsBackoff::new(
+                    1.0, 60.0, 2.0,
+                )),
+                "GoogleCloudWorkflows",
+                [1000, 2000, 4000],
+            ),
+            (
+                r#"{"type":"explicit_schedule","delays":["1s","5s","30s"]}"#,
+                BackoffConfig::ExplicitSchedule {
+                    delays: alloc::vec![
+                        Duration::from_secs(1),
+                        Duration::from_secs(5),
+                        Duration::from_secs(30),
+                    ],
+                },
+                "ExplicitSchedule",
+                [1000, 5000, 30000],
+            ),
+        ] {
+            assert_eq!(serde_json::from_str::<BackoffConfig>(json).unwrap(), config);
+            assert_eq!(serde_json::to_string(&config).unwrap(), json);
+
+            let backoff = config.build();
+            assert_eq!(backoff.name(), name);
+            for (i, millis) in delays.iter().enumerate() {
+                assert_eq!(backoff.delay(i + 1), Duration::from_millis(*millis));
+            }
+        }
+    }
+
+    #[test]
+    fn test_deserialize_randomized() {
+        let config: BackoffConfig =
+            serde_json::from_str(r#"{"type":"decorrelated_jitter","base":"100ms","cap":"10s"}"#)
+                .unwrap();
+        let backoff = config.build();
+        assert_eq!(backoff.name(), "DecorrelatedJitter");
+        let delay = backoff.delay(1);
+        assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(300));
+
+        let config: BackoffConfig = serde_json::from_str(
+            r#"{"type":"jitter","mode":{"proportional":0.2},"inner":{"type":"constant","delay":"1s"}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            BackoffConfig::Jitter {
+                inner: Box::new(BackoffConfig::Constant(ConstantBackoff::new(
+                    Duration::from_secs(1)
+                ))),
+                mode: JitterMode::Proportional(0.2),
+            }
+        );
+        let backoff = config.build();
+        assert_eq!(backoff.name(), "Jitter");
+        let delay = backoff.delay(1);
+        assert!(delay >= Duration::from_millis(800) && delay <= Duration::from_millis(1200));
+
+        let config: BackoffConfig = serde_json::from_str(
+            r#"{"type":"jitter","mode":"full","inner":{"type":"constant","delay":"1s"}}"#,
+        )
+        .unwrap();
+        assert!(config.build().delay(1) <= Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_deserialize_combinators() {
+        let json = r#"{"type":"floor","inner":{"type":"cap","inner":{"type":"constant","delay":"1m"},"max":"30s"},"min":"45s"}"#;
+        let config: BackoffConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&config).unwrap(), json);
+
+        let backoff = config.build();
+        assert_eq!(backoff.name(), "Floor");
+        assert_eq!(backoff.delay(1), Duration::from_secs(45));
+
+        let json = r#"{"type":"offset","inner":{"type":"scale","inner":{"type":"constant","delay":"1s"},"factor":0.5},"offset":"100ms"}"#;
+        let config: BackoffConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&config).unwrap(), json);
+
+        let backoff = config.build();
+        assert_eq!(backoff.name(), "Offset");
+        assert_eq!(backoff.delay(1), Duration::from_millis(600));
+    }
+
+    #[test]
+    fn test_deserialize_invalid() {
+        for json in [
+            r#"{"type":"unknown"}"#,
+            r#"{"type":"constant","delay":"1 lightyear"}"#,
+            r#"{"type":"constant"}"#,
+        ] {
+            assert!(
+                serde_json::from_str::<BackoffConfig>(json).is_err(),
+                "{json}"
+            );
+        }
+    }
+}
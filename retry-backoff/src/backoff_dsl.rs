@@ -0,0 +1,971 @@
+//! Compact string form of [`BackoffConfig`], for CLI flags and env vars.
+//!
+//! ```text
+//! backoff  = base ( "+" modifier )*
+//! base     = const(D)
+//!          | linear(D, step=D, max=D)
+//!          | fib(D, max=D)
+//!          | exp(D, xN, max=D)
+//!          | gcw(D, xN, max=D)
+//!          | schedule(D, D, ...)
+//!          | decorrelated(D, cap=D)
+//! modifier = jitter(full | equal | N% | N) | max(D) | min(D) | scale(xN) | offset(D)
+//! ```
+//!
+//! e.g. `exp(100ms, x2, max=30s) + jitter(20%)`. Keyword arguments are optional except gcw's max.
+//! Durations are integers or decimals followed by `ns`, `us`, `ms`, `s`, `m` or `h`.
+//!
+//! `Display` of [`BackoffConfig`] writes the canonical form, which parses back to an equal value,
+//! or for jitter ratios and scale factors the parser rejects, to one delaying the same.
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{fmt, str::FromStr, time::Duration};
+
+use crate::{
+    backoff_config::BackoffConfig,
+    backoffs::{
+        ConstantBackoff, ExponentialBackoff, FibonacciBackoff, GoogleCloudWorkflowsBackoff,
+        JitterMode, LinearBackoff, exponential::MULTIPLIER_MILLI_ONE,
+    },
+    retry_backoff::RetryBackoff,
+    utils::nanos_to_duration,
+};
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+// largest first, for Display
+const UNITS: &[(&str, u128)] = &[
+    ("h", 3600 * NANOS_PER_SEC),
+    ("m", 60 * NANOS_PER_SEC),
+    ("s", NANOS_PER_SEC),
+    ("ms", 1_000_000),
+    ("us", 1_000),
+    ("ns", 1),
+];
+
+// enough fractional digits for ns precision in every unit
+const MAX_FRACTION_DIGITS: usize = 13;
+
+const DEFAULT_EXPONENTIAL_MULTIPLIER_MILLI: u32 = 2 * MULTIPLIER_MILLI_ONE;
+
+/// parses and builds, see the module docs for the grammar
+pub fn parse(s: &str) -> Result<Box<dyn RetryBackoff + Send + Sync>, ParseError> {
+    s.parse::<BackoffConfig>().map(|config| config.build())
+}
+
+//
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    position: usize,
+    kind: ParseErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    UnexpectedEnd,
+    UnexpectedChar(char),
+    UnknownFunction(String),
+    /// a modifier such as `jitter` where a backoff is expected
+    ExpectedBackoff(String),
+    /// a backoff such as `exp` after `+`
+    ExpectedModifier(String),
+    UnknownArgument(String),
+    DuplicateArgument(String),
+    MissingArgument(&'static str),
+    TooManyArguments,
+    InvalidDuration,
+    InvalidDurationUnit,
+    DurationOutOfRange,
+    InvalidMultiplier,
+    InvalidJitter,
+}
+
+impl ParseError {
+    fn new(position: usize, kind: ParseErrorKind) -> Self {
+        Self { position, kind }
+    }
+
+    /// byte offset into the input
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => write!(f, "unexpected end of input"),
+            Self::UnexpectedChar(c) => write!(f, "unexpected character `{c}`"),
+            Self::UnknownFunction(name) => write!(f, "unknown backoff or modifier `{name}`"),
+            Self::ExpectedBackoff(name) => write!(f, "expected a backoff, found modifier `{name}`"),
+            Self::ExpectedModifier(name) => {
+                write!(f, "expected a modifier after `+`, found backoff `{name}`")
+            }
+            Self::UnknownArgument(name) => write!(f, "unknown argument `{name}`"),
+            Self::DuplicateArgument(name) => write!(f, "duplicate argument `{name}`"),
+            Self::MissingArgument(name) => write!(f, "missing argument `{name}`"),
+            Self::TooManyArguments => write!(f, "too many arguments"),
+            Self::InvalidDuration => write!(f, "invalid duration"),
+            Self::InvalidDurationUnit => {
+                write!(f, "expected a duration unit (ns, us, ms, s, m, h)")
+            }
+            Self::DurationOutOfRange => write!(f, "duration out of range"),
+            Self::InvalidMultiplier => write!(f, "expected a multiplier such as `x2` or `x1.5`"),
+            Self::InvalidJitter => {
+                write!(f, "expected `full`, `equal`, a percentage or a ratio")
+            }
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.kind, self.position)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+//
+impl FromStr for BackoffConfig {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { input: s, pos: 0 };
+
+        let mut config = base(&parser.call()?)?;
+        loop {
+            parser.skip_whitespace();
+            match parser.peek() {
+                None => return Ok(config),
+                Some('+') => {
+                    parser.pos += 1;
+                    config = modifier(config, &parser.call()?)?;
+                }
+                Some(_) => return Err(parser.unexpected()),
+            }
+        }
+    }
+}
+
+fn base(call: &Call<'_>) -> Result<BackoffConfig, ParseError> {
+    let config = match call.name {
+        "const" => {
+            call.check(1, &[])?;
+            BackoffConfig::Constant(ConstantBackoff::new(call.required(0, "delay")?.duration()?))
+        }
+        "linear" => {
+            call.check(1, &["step", "max"])?;
+            let initial = call.required(0, "initial")?.duration()?;
+            BackoffConfig::Linear(LinearBackoff::new(
+                initial,
+                call.keyword_duration("step")?.unwrap_or(initial),
+                call.keyword_duration("max")?.unwrap_or(Duration::MAX),
+            ))
+        }
+        "fib" => {
+            call.check(1, &["max"])?;
+            BackoffConfig::Fibonacci(FibonacciBackoff::new(
+                call.required(0, "initial")?.duration()?,
+                call.keyword_duration("max")?.unwrap_or(Duration::MAX),
+            ))
+        }
+        "exp" => {
+            call.check(2, &["max"])?;
+            let multiplier_milli = match call.positional(1) {
+                Some(arg) => {
+                    let milli = (arg.multiplier()? * MULTIPLIER_MILLI_ONE as f64).round();
+                    if milli > u32::MAX as f64 {
+                        return Err(ParseError::new(arg.pos, ParseErrorKind::InvalidMultiplier));
+                    }
+                    milli as u32
+                }
+                None => DEFAULT_EXPONENTIAL_MULTIPLIER_MILLI,
+            };
+            BackoffConfig::Exponential(ExponentialBackoff::with_multiplier_milli(
+                call.required(0, "initial")?.duration()?,
+                multiplier_milli,
+                call.keyword_duration("max")?.unwrap_or(Duration::MAX),
+            ))
+        }
+        "gcw" => {
+            call.check(2, &["max"])?;
+            let initial = call.required(0, "initial")?.duration()?;
+            let multiplier = call.required(1, "multiplier")?.multiplier()?;
+            let max = call
+                .keyword_duration("max")?
+                .ok_or_else(|| ParseError::new(call.end, ParseErrorKind::MissingArgument("max")))?;
+            BackoffConfig::GoogleCloudWorkflows(GoogleCloudWorkflowsBackoff::from_durations(
+                initial, max, multiplier,
+            ))
+        }
+        "schedule" => {
+            call.check(usize::MAX, &[])?;
+            BackoffConfig::ExplicitSchedule {
+                delays: call
+                    .args
+                    .iter()
+                    .map(Arg::duration)
+                    .collect::<Result<_, _>>()?,
+            }
+        }
+        "decorrelated" => {
+            call.check(1, &["cap"])?;
+            BackoffConfig::DecorrelatedJitter {
+                base: call.required(0, "base")?.duration()?,
+                cap: call.keyword_duration("cap")?.unwrap_or(Duration::MAX),
+            }
+        }
+        name if is_modifier(name) => {
+            return Err(ParseError::new(
+                call.pos,
+                ParseErrorKind::ExpectedBackoff(name.into()),
+            ));
+        }
+        name => {
+            return Err(ParseError::new(
+                call.pos,
+                ParseErrorKind::UnknownFunction(name.into()),
+            ));
+        }
+    };
+    Ok(config)
+}
+
+fn modifier(inner: BackoffConfig, call: &Call<'_>) -> Result<BackoffConfig, ParseError> {
+    let inner = Box::new(inner);
+    let config = match call.name {
+        "jitter" => {
+            call.check(1, &[])?;
+            BackoffConfig::Jitter {
+                inner,
+                mode: call.required(0, "mode")?.jitter_mode()?,
+            }
+        }
+        "max" => {
+            call.check(1, &[])?;
+            BackoffConfig::Cap {
+                inner,
+                max: call.required(0, "max")?.duration()?,
+            }
+        }
+        "min" => {
+            call.check(1, &[])?;
+            BackoffConfig::Floor {
+                inner,
+                min: call.required(0, "min")?.duration()?,
+            }
+        }
+        "scale" => {
+            call.check(1, &[])?;
+            BackoffConfig::Scale {
+                inner,
+                factor: call.required(0, "factor")?.multiplier()?,
+            }
+        }
+        "offset" => {
+            call.check(1, &[])?;
+            BackoffConfig::Offset {
+                inner,
+                offset: call.required(0, "offset")?.duration()?,
+            }
+        }
+        name if is_base(name) => {
+            return Err(ParseError::new(
+                call.pos,
+                ParseErrorKind::ExpectedModifier(name.into()),
+            ));
+        }
+        name => {
+            return Err(ParseError::new(
+                call.pos,
+                ParseErrorKind::UnknownFunction(name.into()),
+            ));
+        }
+    };
+    Ok(config)
+}
+
+fn is_base(name: &str) -> bool {
+    matches!(
+        name,
+        "const" | "linear" | "fib" | "exp" | "gcw" | "schedule" | "decorrelated"
+    )
+}
+
+fn is_modifier(name: &str) -> bool {
+    matches!(name, "jitter" | "max" | "min" | "scale" | "offset")
+}
+
+//
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek().filter(|c| c.is_whitespace()) {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn unexpected(&self) -> ParseError {
+        match self.peek() {
+            Some(c) => ParseError::new(self.pos, ParseErrorKind::UnexpectedChar(c)),
+            None => ParseError::new(self.pos, ParseErrorKind::UnexpectedEnd),
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if self.peek() == Some(expected) {
+            self.pos += expected.len_utf8();
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    /// a run of chars up to whitespace or punctuation, None if empty
+    fn token(&mut self) -> Option<(usize, &'a str)> {
+        self.skip_whitespace();
+        let start = self.pos;
+        while let Some(c) = self
+            .peek()
+            .filter(|c| !c.is_whitespace() && !matches!(c, '(' | ')' | ',' | '=' | '+'))
+        {
+            self.pos += c.len_utf8();
+        }
+        (self.pos > start).then(|| (start, &self.input[start..self.pos]))
+    }
+
+    fn call(&mut self) -> Result<Call<'a>, ParseError> {
+        let (pos, name) = self.token().ok_or_else(|| self.unexpected())?;
+        self.expect('(')?;
+
+        let mut args = Vec::new();
+        self.skip_whitespace();
+        if self.peek() != Some(')') {
+            loop {
+                args.push(self.arg()?);
+                self.skip_whitespace();
+                match self.peek() {
+                    Some(',') => self.pos += 1,
+                    Some(')') => break,
+                    _ => return Err(self.unexpected()),
+                }
+            }
+        }
+        let end = self.pos;
+        self.pos += 1;
+
+        Ok(Call {
+            pos,
+            name,
+            args,
+            end,
+        })
+    }
+
+    fn arg(&mut self) -> Result<Arg<'a>, ParseError> {
+        let (pos, value) = self.token().ok_or_else(|| self.unexpected())?;
+
+        self.skip_whitespace();
+        if self.peek() != Some('=') {
+            return Ok(Arg {
+                key: None,
+                pos,
+                value,
+            });
+        }
+        self.pos += 1;
+
+        let key = Some((pos, value));
+        let (pos, value) = self.token().ok_or_else(|| self.unexpected())?;
+        Ok(Arg { key, pos, value })
+    }
+}
+
+//
+struct Call<'a> {
+    pos: usize,
+    name: &'a str,
+    args: Vec<Arg<'a>>,
+    /// position of `)`
+    end: usize,
+}
+
+impl<'a> Call<'a> {
+    fn check(&self, max_positional: usize, keywords: &[&str]) -> Result<(), ParseError> {
+        let mut positional = 0;
+        for (i, arg) in self.args.iter().enumerate() {
+            match arg.key {
+                None => {
+                    positional += 1;
+                    if positional > max_positional {
+                        return Err(ParseError::new(arg.pos, ParseErrorKind::TooManyArguments));
+                    }
+                }
+                Some((pos, key)) => {
+                    if !keywords.contains(&key) {
+                        return Err(ParseError::new(
+                            pos,
+                            ParseErrorKind::UnknownArgument(key.into()),
+                        ));
+                    }
+                    if self.args[..i]
+                        .iter()
+                        .any(|prev| prev.key.map(|(_, k)| k) == Some(key))
+                    {
+                        return Err(ParseError::new(
+                            pos,
+                            ParseErrorKind::DuplicateArgument(key.into()),
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn positional(&self, index: usize) -> Option<&Arg<'a>> {
+        self.args.iter().filter(|arg| arg.key.is_none()).nth(index)
+    }
+
+    fn required(&self, index: usize, name: &'static str) -> Result<&Arg<'a>, ParseError> {
+        self.positional(index)
+            .ok_or_else(|| ParseError::new(self.end, ParseErrorKind::MissingArgument(name)))
+    }
+
+    fn keyword_duration(&self, key: &str) -> Result<Option<Duration>, ParseError> {
+        self.args
+            .iter()
+            .find(|arg| arg.key.map(|(_, k)| k) == Some(key))
+            .map(Arg::duration)
+            .transpose()
+    }
+}
+
+//
+struct Arg<'a> {
+    key: Option<(usize, &'a str)>,
+    pos: usize,
+    value: &'a str,
+}
+
+impl Arg<'_> {
+    fn error(&self, offset: usize, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(self.pos + offset, kind)
+    }
+
+    fn duration(&self) -> Result<Duration, ParseError> {
+        let number_len = self
+            .value
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(self.value.len());
+        let (number, unit) = self.value.split_at(number_len);
+
+        let unit_nanos = UNITS
+            .iter()
+            .find(|(name, _)| *name == unit)
+            .map(|(_, nanos)| *nanos)
+            .ok_or_else(|| self.error(number_len, ParseErrorKind::InvalidDurationUnit))?;
+
+        let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+        if (integer.is_empty() && fraction.is_empty()) || fraction.contains('.') {
+            return Err(self.error(0, ParseErrorKind::InvalidDuration));
+        }
+
+        let out_of_range = || self.error(0, ParseErrorKind::DurationOutOfRange);
+
+        let integer = match integer {
+            "" => 0,
+            integer => integer.parse::<u128>().map_err(|_| out_of_range())?,
+        };
+        let fraction = &fraction[..fraction.len().min(MAX_FRACTION_DIGITS)];
+        let fraction_nanos = match fraction {
+            "" => 0,
+            fraction => {
+                fraction.parse::<u128>().map_err(|_| out_of_range())? * unit_nanos
+                    / 10_u128.pow(fraction.len() as u32)
+            }
+        };
+
+        let nanos = integer
+            .checked_mul(unit_nanos)
+            .and_then(|nanos| nanos.checked_add(fraction_nanos))
+            .filter(|nanos| *nanos <= Duration::MAX.as_nanos())
+            .ok_or_else(out_of_range)?;
+
+        Ok(nanos_to_duration(nanos))
+    }
+
+    fn multiplier(&self) -> Result<f64, ParseError> {
+        self.value
+            .strip_prefix('x')
+            .and_then(|value| value.parse::<f64>().ok())
+            .filter(|multiplier| multiplier.is_finite() && *multiplier >= 0.0)
+            .ok_or_else(|| self.error(0, ParseErrorKind::InvalidMultiplier))
+    }
+
+    fn jitter_mode(&self) -> Result<JitterMode, ParseError> {
+        let ratio = match self.value {
+            "full" => return Ok(JitterMode::Full),
+            "equal" => return Ok(JitterMode::Equal),
+            value => match value.strip_suffix('%') {
+                Some(percent) => percent.parse::<f64>().map(|percent| percent / 100.0),
+                None => value.parse::<f64>(),
+            },
+        };
+
+        ratio
+            .ok()
+            .filter(|ratio| ratio.is_finite())
+            .map(JitterMode::Proportional)
+            .ok_or_else(|| self.error(0, ParseErrorKind::InvalidJitter))
+    }
+}
+
+//
+impl fmt::Display for BackoffConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Constant(backoff) => write!(f, "const({})", DisplayDuration(backoff.delay)),
+            Self::Linear(backoff) => {
+                write!(f, "linear({}", DisplayDuration(backoff.initial))?;
+                if backoff.step != backoff.initial {
+                    write!(f, ", step={}", DisplayDuration(backoff.step))?;
+                }
+                write_max(f, "max", backoff.max)?;
+                write!(f, ")")
+            }
+            Self::Fibonacci(backoff) => {
+                write!(f, "fib({}", DisplayDuration(backoff.initial))?;
+                write_max(f, "max", backoff.max)?;
+                write!(f, ")")
+            }
+            Self::Exponential(backoff) => {
+                write!(
+                    f,
+                    "exp({}, x{}",
+                    DisplayDuration(backoff.initial),
+                    backoff.multiplier_milli as f64 / MULTIPLIER_MILLI_ONE as f64
+                )?;
+                write_max(f, "max", backoff.max)?;
+                write!(f, ")")
+            }
+            Self::GoogleCloudWorkflows(backoff) => write!(
+                f,
+                "gcw({}, x{}, max={})",
+                DisplayDuration(backoff.initial_delay()),
+                backoff.multiplier,
+                DisplayDuration(backoff.max_delay())
+            ),
+            Self::ExplicitSchedule { delays } => {
+                write!(f, "schedule(")?;
+                for (i, delay) in delays.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", DisplayDuration(*delay))?;
+                }
+                write!(f, ")")
+            }
+            Self::DecorrelatedJitter { base, cap } => {
+                write!(f, "decorrelated({}", DisplayDuration(*base))?;
+                write_max(f, "cap", *cap)?;
+                write!(f, ")")
+            }
+            Self::Jitter { inner, mode } => match mode {
+                JitterMode::Full => write!(f, "{inner} + jitter(full)"),
+                JitterMode::Equal => write!(f, "{inner} + jitter(equal)"),
+                JitterMode::Proportional(ratio) => {
+                    // NaN and infinities as the ratio Jitter applies, which parses
+                    let ratio = &if ratio.is_finite() {
+                        *ratio
+                    } else if ratio.is_nan() {
+                        0.0
+                    } else {
+                        ratio.clamp(0.0, 1.0)
+                    };
+                    // the shortest percent that parses back to the same ratio, else the ratio
+                    let percent = ratio * 100.0;
+                    match (0..=17)
+                        .map(|precision| alloc::format!("{percent:.precision$}"))
+                        .find(|s| s.parse::<f64>().map(|p| p / 100.0) == Ok(*ratio))
+                    {
+                        Some(percent) => write!(f, "{inner} + jitter({percent}%)"),
+                        None => write!(f, "{inner} + jitter({ratio})"),
+                    }
+                }
+            },
+            Self::Cap { inner, max } => write!(f, "{inner} + max({})", DisplayDuration(*max)),
+            Self::Floor { inner, min } => write!(f, "{inner} + min({})", DisplayDuration(*min)),
+            Self::Scale { inner, factor } => {
+                // negative and NaN scale to zero, infinity as far as f64::MAX, which parse
+                let factor = if factor.is_nan() || *factor < 0.0 {
+                    0.0
+                } else {
+                    factor.min(f64::MAX)
+                };
+                write!(f, "{inner} + scale(x{factor})")
+            }
+            Self::Offset { inner, offset } => {
+                write!(f, "{inner} + offset({})", DisplayDuration(*offset))
+            }
+        }
+    }
+}
+
+fn write_max(f: &mut fmt::Formatter<'_>, key: &str, max: Duration) -> fmt::Result {
+    if max == Duration::MAX {
+        return Ok(());
+    }
+    write!(f, ", {key}={}", DisplayDuration(max))
+}
+
+/// in the largest unit that keeps it an integer
+struct DisplayDuration(Duration);
+
+impl fmt::Display for DisplayDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = self.0.as_nanos();
+        if nanos == 0 {
+            return write!(f, "0s");
+        }
+
+        let (unit, unit_nanos) = UNITS
+            .iter()
+            .find(|(_, unit_nanos)| nanos.is_multiple_of(*unit_nanos))
+            .expect("ns divides everything");
+        write!(f, "{}{unit}", nanos / unit_nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::string::ToString as _;
+
+    #[test]
+    fn test_parse() {
+        for (s, config) in [
+            (
+                "const(1s)",
+                BackoffConfig::Constant(ConstantBackoff::new(Duration::from_secs(1))),
+            ),
+            (
+                "linear(100ms, step=50ms, max=10s)",
+                BackoffConfig::Linear(LinearBackoff::new(
+                    Duration::from_millis(100),
+                    Duration::from_millis(50),
+                    Duration::from_secs(10),
+                )),
+            ),
+            (
+                "linear(1.5s)",
+                BackoffConfig::Linear(LinearBackoff::new(
+                    Duration::from_millis(1500),
+                    Duration::from_millis(1500),
+                    Duration::MAX,
+                )),
+            ),
+            (
+                "fib(100ms, max=1m)",
+                BackoffConfig::Fibonacci(FibonacciBackoff::new(
+                    Duration::from_millis(100),
+                    Duration::from_secs(60),
+                )),
+            ),
+            (
+                "exp(100ms, x1.5, max=30s)",
+                BackoffConfig::Exponential(ExponentialBackoff::with_multiplier_milli(
+                    Duration::from_millis(100),
+                    1500,
+                    Duration::from_secs(30),
+                )),
+            ),
+            (
+                "exp(1h)",
+                BackoffConfig::Exponential(ExponentialBackoff::new(
+                    Duration::from_secs(3600),
+                    2,
+                    Duration::MAX,
+                )),
+            ),
+            (
+                "gcw(1s, x1.25, max=60s)",
+                BackoffConfig::GoogleCloudWorkflows(GoogleCloudWorkflowsBackoff::default()),
+            ),
+            (
+                "schedule(1s, 5s, 30s)",
+                BackoffConfig::ExplicitSchedule {
+                    delays: alloc::vec![
+                        Duration::from_secs(1),
+                        Duration::from_secs(5),
+                        Duration::from_secs(30),
+                    ],
+                },
+            ),
+            (
+                "schedule()",
+                BackoffConfig::ExplicitSchedule {
+                    delays: alloc::vec![],
+                },
+            ),
+            (
+                "decorrelated(100ms, cap=10s)",
+                BackoffConfig::DecorrelatedJitter {
+                    base: Duration::from_millis(100),
+                    cap: Duration::from_secs(10),
+                },
+            ),
+            (
+                "  exp( 100ms ,x2 , max = 30s )+jitter( 20% ) ",
+                BackoffConfig::Jitter {
+                    inner: Box::new(BackoffConfig::Exponential(ExponentialBackoff::new(
+                        Duration::from_millis(100),
+                        2,
+                        Duration::from_secs(30),
+                    ))),
+                    mode: JitterMode::Proportional(0.2),
+                },
+            ),
+            (
+                "const(1m) + jitter(full) + max(45s) + min(50s)",
+                BackoffConfig::Floor {
+                    inner: Box::new(BackoffConfig::Cap {
+                        inner: Box::new(BackoffConfig::Jitter {
+                            inner: Box::new(BackoffConfig::Constant(ConstantBackoff::new(
+                                Duration::from_secs(60),
+                            ))),
+                            mode: JitterMode::Full,
+                        }),
+                        max: Duration::from_secs(45),
+                    }),
+                    min: Duration::from_secs(50),
+                },
+            ),
+            (
+                "const(1s) + scale(x1.5) + offset(10ms)",
+                BackoffConfig::Offset {
+                    inner: Box::new(BackoffConfig::Scale {
+                        inner: Box::new(BackoffConfig::Constant(ConstantBackoff::new(
+                            Duration::from_secs(1),
+                        ))),
+                        factor: 1.5,
+                    }),
+                    offset: Duration::from_millis(10),
+                },
+            ),
+        ] {
+            assert_eq!(s.parse::<BackoffConfig>(), Ok(config), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        for s in [
+            "const(1s)",
+            "const(0s)",
+            "const(18446744073709551615999999999ns)",
+            "linear(100ms, step=50ms, max=10s)",
+            "linear(1500ms)",
+            "fib(100ms, max=1m)",
+            "exp(100ms, x1.5, max=30s)",
+            "exp(1h, x2)",
+            "gcw(1s, x1.25, max=1m)",
+            "gcw(100ms, x2, max=90s)",
+            "schedule(1s, 5s, 30s)",
+            "schedule()",
+            "decorrelated(100ms, cap=10s)",
+            "exp(100ms, x2, max=30s) + jitter(20%)",
+            "const(1s) + jitter(7%)",
+            "const(1m) + jitter(equal) + max(45s) + min(50s)",
+            "const(1001us)",
+            "exp(1s, x2) + scale(x0.5) + offset(250ms)",
+        ] {
+            let config = s.parse::<BackoffConfig>().unwrap();
+            assert_eq!(config.to_string(), s);
+            assert_eq!(config.to_string().parse::<BackoffConfig>(), Ok(config));
+        }
+
+        for (s, canonical) in [
+            ("exp(100ms)", "exp(100ms, x2)"),
+            ("linear(100ms, step=100ms)", "linear(100ms)"),
+            ("const(1.5s)", "const(1500ms)"),
+            ("const(120s)", "const(2m)"),
+            ("const(.5ms)", "const(500us)"),
+            ("const(0.1ns)", "const(0s)"),
+            ("const(1s)+jitter(0.5)", "const(1s) + jitter(50%)"),
+            (
+                "const(1s) + jitter(0.123456789)",
+                "const(1s) + jitter(12.3456789%)",
+            ),
+        ] {
+            assert_eq!(
+                s.parse::<BackoffConfig>().unwrap().to_string(),
+                canonical,
+                "{s}"
+            );
+        }
+
+        // built through the API or serde, printed as values the parser accepts
+        let constant = || {
+            Box::new(BackoffConfig::Constant(ConstantBackoff::new(
+                Duration::from_secs(1),
+            )))
+        };
+        let jitter = |ratio| BackoffConfig::Jitter {
+            inner: constant(),
+            mode: JitterMode::Proportional(ratio),
+        };
+        let scale = |factor| BackoffConfig::Scale {
+            inner: constant(),
+            factor,
+        };
+        let max_factor = alloc::format!("const(1s) + scale(x{})", f64::MAX);
+        for (config, canonical) in [
+            (jitter(f64::NAN), "const(1s) + jitter(0%)"),
+            (jitter(f64::INFINITY), "const(1s) + jitter(100%)"),
+            (jitter(f64::NEG_INFINITY), "const(1s) + jitter(0%)"),
+            (scale(-1.0), "const(1s) + scale(x0)"),
+            (scale(f64::NAN), "const(1s) + scale(x0)"),
+            (scale(f64::INFINITY), max_factor.as_str()),
+        ] {
+            assert_eq!(config.to_string(), canonical);
+
+            let parsed = config.to_string().parse::<BackoffConfig>().unwrap();
+            assert_eq!(parsed.to_string(), canonical);
+            let (built, parsed) = (config.build(), parsed.build());
+            for attempts in 1..=3 {
+                let bounds = built.try_delay_bounds(attempts);
+                assert_eq!(parsed.try_delay_bounds(attempts), bounds, "{canonical}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        for (s, position, kind) in [
+            ("", 0, ParseErrorKind::UnexpectedEnd),
+            ("exp", 3, ParseErrorKind::UnexpectedEnd),
+            ("exp 100ms", 4, ParseErrorKind::UnexpectedChar('1')),
+            ("exp(100ms", 9, ParseErrorKind::UnexpectedEnd),
+            ("exp(100ms,)", 10, ParseErrorKind::UnexpectedChar(')')),
+            (
+                "expo(100ms)",
+                0,
+                ParseErrorKind::UnknownFunction("expo".into()),
+            ),
+            (
+                "jitter(20%)",
+                0,
+                ParseErrorKind::ExpectedBackoff("jitter".into()),
+            ),
+            (
+                "const(1s) + exp(1s)",
+                12,
+                ParseErrorKind::ExpectedModifier("exp".into()),
+            ),
+            (
+                "const(1s) + wobble(1s)",
+                12,
+                ParseErrorKind::UnknownFunction("wobble".into()),
+            ),
+            (
+                "const(1s) const(1s)",
+                10,
+                ParseErrorKind::UnexpectedChar('c'),
+            ),
+            ("const(1s) +", 11, ParseErrorKind::UnexpectedEnd),
+            (
+                "exp(100ms, x2, cap=30s)",
+                15,
+                ParseErrorKind::UnknownArgument("cap".into()),
+            ),
+            (
+                "exp(100ms, max=1s, max=2s)",
+                19,
+                ParseErrorKind::DuplicateArgument("max".into()),
+            ),
+            ("const()", 6, ParseErrorKind::MissingArgument("delay")),
+            ("gcw(1s, x2)", 10, ParseErrorKind::MissingArgument("max")),
+            ("const(1s, 2s)", 10, ParseErrorKind::TooManyArguments),
+            ("const(ms)", 6, ParseErrorKind::InvalidDuration),
+            ("const(1.2.3s)", 6, ParseErrorKind::InvalidDuration),
+            ("const(100)", 9, ParseErrorKind::InvalidDurationUnit),
+            ("exp(100 ms)", 8, ParseErrorKind::UnexpectedChar('m')),
+            (
+                "exp(1s, max=30sec)",
+                14,
+                ParseErrorKind::InvalidDurationUnit,
+            ),
+            (
+                "const(18446744073709551616s)",
+                6,
+                ParseErrorKind::DurationOutOfRange,
+            ),
+            ("exp(100ms, 2)", 11, ParseErrorKind::InvalidMultiplier),
+            ("exp(100ms, x-2)", 11, ParseErrorKind::InvalidMultiplier),
+            (
+                "exp(100ms, x5000000)",
+                11,
+                ParseErrorKind::InvalidMultiplier,
+            ),
+            (
+                "const(1s) + jitter(lots)",
+                19,
+                ParseErrorKind::InvalidJitter,
+            ),
+            (
+                "const(1s) + jitter(inf%)",
+                19,
+                ParseErrorKind::InvalidJitter,
+            ),
+        ] {
+            let err = s.parse::<BackoffConfig>().unwrap_err();
+            assert_eq!(err.position(), position, "{s}");
+            assert_eq!(err.kind(), &kind, "{s}");
+        }
+
+        assert_eq!(
+            "exp(1s, max=30sec)"
+                .parse::<BackoffConfig>()
+                .unwrap_err()
+                .to_string(),
+            "expected a duration unit (ns, us, ms, s, m, h) at position 14"
+        );
+    }
+
+    #[test]
+    fn test_parse_and_build() {
+        let backoff = parse("exp(100ms, x2, max=1s)").unwrap();
+        assert_eq!(backoff.name(), "Exponential");
+        for (attempts, millis) in [(1, 100), (2, 200), (4, 800), (5, 1000)] {
+            assert_eq!(backoff.delay(attempts), Duration::from_millis(millis));
+        }
+
+        let backoff = parse("exp(100ms, x2, max=30s) + jitter(20%)").unwrap();
+        assert_eq!(backoff.name(), "Jitter");
+        let delay = backoff.delay(1);
+        assert!(delay >= Duration::from_millis(80) && delay <= Duration::from_millis(120));
+
+        let backoff = parse("schedule(1s, 5s) + min(2s)").unwrap();
+        assert_eq!(backoff.delay(1), Duration::from_secs(2));
+        assert_eq!(backoff.delay(2), Duration::from_secs(5));
+        assert_eq!(backoff.try_delay(3), None);
+
+        assert!(parse("exp(100ms").is_err());
+    }
+}
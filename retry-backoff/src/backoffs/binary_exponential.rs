@@ -0,0 +1,154 @@
+//! [Truncated binary exponential backoff](https://en.wikipedia.org/wiki/Exponential_backoff#Binary_exponential_backoff)
+//!
+//! After the c-th collision wait `slot * random_between(0, 2^min(c, truncation) - 1)`.
+//! Ethernet uses a 51.2µs slot and a truncation of 10.
+
+use core::{fmt, time::Duration};
+
+use crate::{random::RandomSource, retry_backoff::RetryBackoff, utils::nanos_to_duration};
+
+/// truncations above this are treated as this, `2^64 - 1` slots
+pub const MAX_TRUNCATION: u32 = 64;
+
+//
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Backoff<R> {
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub slot: Duration,
+    pub truncation: u32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    rng: R,
+}
+
+impl<R> Backoff<R> {
+    pub const fn new(slot: Duration, truncation: u32, rng: R) -> Self {
+        Self {
+            slot,
+            truncation,
+            rng,
+        }
+    }
+
+    /// the largest slot count for attempts, `2^c - 1`
+    /// attempts 0 is treated as 1
+    pub fn max_slots(&self, attempts: usize) -> u64 {
+        let exponent = attempts
+            .max(1)
+            .min(self.truncation.min(MAX_TRUNCATION) as usize) as u32;
+
+        u64::MAX.checked_shr(u64::BITS - exponent).unwrap_or(0)
+    }
+
+    fn slots_to_duration(&self, slots: u64) -> Duration {
+        nanos_to_duration(self.slot.as_nanos().saturating_mul(slots as u128))
+    }
+}
+
+//
+impl<R> RetryBackoff for Backoff<R>
+where
+    R: RandomSource,
+{
+    fn delay(&self, attempts: usize) -> Duration {
+        // 2^c - 1 is an all-ones mask, so masking keeps the slot count uniform
+        self.slots_to_duration(self.rng.next_u64() & self.max_slots(attempts))
+    }
+
+    fn try_delay_bounds(&self, attempts: usize) -> Option<(Duration, Duration)> {
+        Some((
+            Duration::ZERO,
+            self.slots_to_duration(self.max_slots(attempts)),
+        ))
+    }
+
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        params("slot", &self.slot);
+        params("truncation", &self.truncation);
+    }
+
+    fn name(&self) -> &str {
+        "BinaryExponential"
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::random::SeededRandom;
+
+    const SLOT: Duration = Duration::from_nanos(51_200);
+
+    #[test]
+    fn test_max_slots() {
+        let backoff = Backoff::new(SLOT, 10, SeededRandom::new(1));
+
+        for (attempts, slots) in &[
+            (0, 1),
+            (1, 1),
+            (2, 3),
+            (3, 7),
+            (10, 1023),
+            (11, 1023),
+            (usize::MAX, 1023),
+        ] {
+            assert_eq!(backoff.max_slots(*attempts), *slots);
+        }
+
+        let backoff = Backoff::new(SLOT, 0, SeededRandom::new(1));
+        assert_eq!(backoff.max_slots(5), 0);
+
+        let backoff = Backoff::new(SLOT, u32::MAX, SeededRandom::new(1));
+        assert_eq!(backoff.max_slots(usize::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn test_impl_retry_backoff() {
+        let backoff = Backoff::new(SLOT, 10, SeededRandom::new(1));
+
+        for attempts in [1, 2, 3, 10, 16] {
+            let max_slots = backoff.max_slots(attempts);
+            let mut seen = [false; 8];
+            for _ in 0..1000 {
+                let dur = RetryBackoff::delay(&backoff, attempts);
+                let slots = dur.as_nanos() / SLOT.as_nanos();
+                assert_eq!(dur.as_nanos() % SLOT.as_nanos(), 0, "{dur:?}");
+                assert!(slots <= max_slots as u128, "{attempts} {slots}");
+                if max_slots < 8 {
+                    seen[slots as usize] = true;
+                }
+            }
+            if max_slots < 8 {
+                // every slot in [0, 2^c - 1] gets picked
+                assert!(seen[..=max_slots as usize].iter().all(|seen| *seen));
+            }
+        }
+
+        assert_eq!(
+            RetryBackoff::try_delay_bounds(&backoff, 3),
+            Some((Duration::ZERO, SLOT * 7))
+        );
+        assert_eq!(RetryBackoff::name(&backoff), "BinaryExponential");
+
+        let backoff = Backoff::new(Duration::MAX, u32::MAX, SeededRandom::new(1));
+        assert_eq!(
+            RetryBackoff::try_delay_bounds(&backoff, usize::MAX),
+            Some((Duration::ZERO, Duration::MAX))
+        );
+    }
+
+    #[test]
+    fn test_deterministic_with_seed() {
+        let a = Backoff::new(SLOT, 10, SeededRandom::new(7));
+        let b = Backoff::new(SLOT, 10, SeededRandom::new(7));
+
+        for attempts in 1..=20 {
+            assert_eq!(
+                RetryBackoff::delay(&a, attempts),
+                RetryBackoff::delay(&b, attempts)
+            );
+        }
+    }
+}
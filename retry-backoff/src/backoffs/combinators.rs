@@ -0,0 +1,575 @@
+//! Wrappers returned by [`RetryBackoffExt`](crate::RetryBackoffExt).
+
+use core::{fmt, time::Duration};
+
+use crate::{
+    description::Description,
+    retry_backoff::{BackoffState, RetryBackoff},
+    utils::secs_f64_to_duration,
+};
+
+//
+/// Caps the inner delay at max.
+#[derive(Clone)]
+pub struct Cap<B> {
+    inner: B,
+    pub max: Duration,
+}
+
+impl<B> Cap<B> {
+    pub fn new(inner: B, max: Duration) -> Self {
+        Self { inner, max }
+    }
+
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+}
+
+impl<B> fmt::Debug for Cap<B>
+where
+    B: RetryBackoff,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cap")
+            .field("inner", &self.inner.name())
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+impl<B> RetryBackoff for Cap<B>
+where
+    B: RetryBackoff,
+{
+    fn delay(&self, attempts: usize) -> Duration {
+        self.inner.delay(attempts).min(self.max)
+    }
+
+    fn delay_with_state(&self, attempts: usize, state: &BackoffState) -> Duration {
+        self.inner.delay_with_state(attempts, state).min(self.max)
+    }
+
+    fn try_delay_with_state(&self, attempts: usize, state: &BackoffState) -> Option<Duration> {
+        self.inner
+            .try_delay_with_state(attempts, state)
+            .map(|delay| delay.min(self.max))
+    }
+
+    fn try_delay_bounds(&self, attempts: usize) -> Option<(Duration, Duration)> {
+        self.inner
+            .try_delay_bounds(attempts)
+            .map(|(min, max)| (min.min(self.max), max.min(self.max)))
+    }
+
+    fn attempt_timeout(&self, attempts: usize) -> Option<Duration> {
+        self.inner.attempt_timeout(attempts)
+    }
+
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        params("inner", &Description::new(&self.inner));
+        params("max", &self.max);
+    }
+
+    fn name(&self) -> &str {
+        "Cap"
+    }
+}
+
+//
+/// Raises the inner delay to at least min.
+#[derive(Clone)]
+pub struct Floor<B> {
+    inner: B,
+    pub min: Duration,
+}
+
+impl<B> Floor<B> {
+    pub fn new(inner: B, min: Duration) -> Self {
+        Self { inner, min }
+    }
+
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+}
+
+impl<B> fmt::Debug for Floor<B>
+where
+    B: RetryBackoff,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Floor")
+            .field("inner", &self.inner.name())
+            .field("min", &self.min)
+            .finish()
+    }
+}
+
+impl<B> RetryBackoff for Floor<B>
+where
+    B: RetryBackoff,
+{
+    fn delay(&self, attempts: usize) -> Duration {
+        self.inner.delay(attempts).max(self.min)
+    }
+
+    fn delay_with_state(&self, attempts: usize, state: &BackoffState) -> Duration {
+        self.inner.delay_with_state(attempts, state).max(self.min)
+    }
+
+    fn try_delay_with_state(&self, attempts: usize, state: &BackoffState) -> Option<Duration> {
+        self.inner
+            .try_delay_with_state(attempts, state)
+            .map(|delay| delay.max(self.min))
+    }
+
+    fn try_delay_bounds(&self, attempts: usize) -> Option<(Duration, Duration)> {
+        self.inner
+            .try_delay_bounds(attempts)
+            .map(|(min, max)| (min.max(self.min), max.max(self.min)))
+    }
+
+    fn attempt_timeout(&self, attempts: usize) -> Option<Duration> {
+        self.inner.attempt_timeout(attempts)
+    }
+
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        params("inner", &Description::new(&self.inner));
+        params("min", &self.min);
+    }
+
+    fn name(&self) -> &str {
+        "Floor"
+    }
+}
+
+//
+/// Multiplies the inner delay by factor, negative and NaN factors give zero.
+#[derive(Clone)]
+pub struct Scale<B> {
+    inner: B,
+    pub factor: f64,
+}
+
+impl<B> Scale<B> {
+    pub fn new(inner: B, factor: f64) -> Self {
+        Self { inner, factor }
+    }
+
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    fn apply(&self, delay: Duration) -> Duration {
+        secs_f64_to_duration(delay.as_secs_f64() * self.factor)
+    }
+}
+
+impl<B> fmt::Debug for Scale<B>
+where
+    B: RetryBackoff,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scale")
+            .field("inner", &self.inner.name())
+            .field("factor", &self.factor)
+            .finish()
+    }
+}
+
+impl<B> RetryBackoff for Scale<B>
+where
+    B: RetryBackoff,
+{
+    fn delay(&self, attempts: usize) -> Duration {
+        self.apply(self.inner.delay(attempts))
+    }
+
+    fn delay_with_state(&self, attempts: usize, state: &BackoffState) -> Duration {
+        self.apply(self.inner.delay_with_state(attempts, state))
+    }
+
+    fn try_delay_with_state(&self, attempts: usize, state: &BackoffState) -> Option<Duration> {
+        self.inner
+            .try_delay_with_state(attempts, state)
+            .map(|delay| self.apply(delay))
+    }
+
+    fn try_delay_bounds(&self, attempts: usize) -> Option<(Duration, Duration)> {
+        self.inner
+            .try_delay_bounds(attempts)
+            .map(|(min, max)| (self.apply(min), self.apply(max)))
+    }
+
+    fn attempt_timeout(&self, attempts: usize) -> Option<Duration> {
+        self.inner.attempt_timeout(attempts)
+    }
+
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        params("inner", &Description::new(&self.inner));
+        params("factor", &self.factor);
+    }
+
+    fn name(&self) -> &str {
+        "Scale"
+    }
+}
+
+//
+/// Adds offset to the inner delay, saturating.
+#[derive(Clone)]
+pub struct Offset<B> {
+    inner: B,
+    pub offset: Duration,
+}
+
+impl<B> Offset<B> {
+    pub fn new(inner: B, offset: Duration) -> Self {
+        Self { inner, offset }
+    }
+
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+}
+
+impl<B> fmt::Debug for Offset<B>
+where
+    B: RetryBackoff,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Offset")
+            .field("inner", &self.inner.name())
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+impl<B> RetryBackoff for Offset<B>
+where
+    B: RetryBackoff,
+{
+    fn delay(&self, attempts: usize) -> Duration {
+        self.inner.delay(attempts).saturating_add(self.offset)
+    }
+
+    fn delay_with_state(&self, attempts: usize, state: &BackoffState) -> Duration {
+        self.inner
+            .delay_with_state(attempts, state)
+            .saturating_add(self.offset)
+    }
+
+    fn try_delay_with_state(&self, attempts: usize, state: &BackoffState) -> Option<Duration> {
+        self.inner
+            .try_delay_with_state(attempts, state)
+            .map(|delay| delay.saturating_add(self.offset))
+    }
+
+    fn try_delay_bounds(&self, attempts: usize) -> Option<(Duration, Duration)> {
+        self.inner.try_delay_bounds(attempts).map(|(min, max)| {
+            (
+                min.saturating_add(self.offset),
+                max.saturating_add(self.offset),
+            )
+        })
+    }
+
+    fn attempt_timeout(&self, attempts: usize) -> Option<Duration> {
+        self.inner.attempt_timeout(attempts)
+    }
+
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        params("inner", &Description::new(&self.inner));
+        params("offset", &self.offset);
+    }
+
+    fn name(&self) -> &str {
+        "Offset"
+    }
+}
+
+//
+/// First `first_attempts` attempts from first, the rest from then renumbered from 1.
+#[derive(Clone)]
+pub struct Chain<A, B> {
+    first: A,
+    pub first_attempts: usize,
+    then: B,
+}
+
+impl<A, B> Chain<A, B> {
+    pub fn new(first: A, first_attempts: usize, then: B) -> Self {
+        Self {
+            first,
+            first_attempts,
+            then,
+        }
+    }
+
+    pub fn first(&self) -> &A {
+        &self.first
+    }
+
+    pub fn then(&self) -> &B {
+        &self.then
+    }
+}
+
+impl<A, B> fmt::Debug for Chain<A, B>
+where
+    A: RetryBackoff,
+    B: RetryBackoff,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Chain")
+            .field("first", &self.first.name())
+            .field("first_attempts", &self.first_attempts)
+            .field("then", &self.then.name())
+            .finish()
+    }
+}
+
+impl<A, B> RetryBackoff for Chain<A, B>
+where
+    A: RetryBackoff,
+    B: RetryBackoff,
+{
+    fn delay(&self, attempts: usize) -> Duration {
+        if attempts <= self.first_attempts {
+            self.first.delay(attempts)
+        } else {
+            self.then.delay(attempts - self.first_attempts)
+        }
+    }
+
+    fn delay_with_state(&self, attempts: usize, state: &BackoffState) -> Duration {
+        if attempts <= self.first_attempts {
+            self.first.delay_with_state(attempts, state)
+        } else {
+            self.then
+                .delay_with_state(attempts - self.first_attempts, state)
+        }
+    }
+
+    fn try_delay_with_state(&self, attempts: usize, state: &BackoffState) -> Option<Duration> {
+        if attempts <= self.first_attempts {
+            self.first.try_delay_with_state(attempts, state)
+        } else {
+            self.then
+                .try_delay_with_state(attempts - self.first_attempts, state)
+        }
+    }
+
+    fn try_delay_bounds(&self, attempts: usize) -> Option<(Duration, Duration)> {
+        if attempts <= self.first_attempts {
+            self.first.try_delay_bounds(attempts)
+        } else {
+            self.then.try_delay_bounds(attempts - self.first_attempts)
+        }
+    }
+
+    fn attempt_timeout(&self, attempts: usize) -> Option<Duration> {
+        if attempts <= self.first_attempts {
+            self.first.attempt_timeout(attempts)
+        } else {
+            self.then.attempt_timeout(attempts - self.first_attempts)
+        }
+    }
+
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        params("first", &Description::new(&self.first));
+        params("first_attempts", &self.first_attempts);
+        params("then", &Description::new(&self.then));
+    }
+
+    fn name(&self) -> &str {
+        "Chain"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{RetryBackoffExt as _, backoffs::LinearBackoff};
+
+    const LINEAR: LinearBackoff = LinearBackoff::new(
+        Duration::from_secs(1),
+        Duration::from_secs(1),
+        Duration::MAX,
+    );
+
+    #[test]
+    fn test_cap() {
+        let backoff = LINEAR.max_delay(Duration::from_secs(3));
+
+        for (attempts, secs) in &[(1, 1), (2, 2), (3, 3), (4, 3), (usize::MAX, 3)] {
+            assert_eq!(
+                RetryBackoff::delay(&backoff, *attempts),
+                Duration::from_secs(*secs)
+            );
+        }
+        assert_eq!(RetryBackoff::name(&backoff), "Cap");
+    }
+
+    #[test]
+    fn test_floor() {
+        let backoff = LINEAR.min_delay(Duration::from_secs(3));
+
+        for (attempts, secs) in &[(1, 3), (2, 3), (3, 3), (4, 4)] {
+            assert_eq!(
+                RetryBackoff::delay(&backoff, *attempts),
+                Duration::from_secs(*secs)
+            );
+        }
+        assert_eq!(RetryBackoff::name(&backoff), "Floor");
+    }
+
+    #[test]
+    fn test_scale() {
+        let backoff = LINEAR.scale(2.0);
+
+        for (attempts, secs) in &[(1, 2), (2, 4), (3, 6)] {
+            assert_eq!(
+                RetryBackoff::delay(&backoff, *attempts),
+                Duration::from_secs(*secs)
+            );
+        }
+        assert_eq!(RetryBackoff::delay(&backoff, usize::MAX), Duration::MAX);
+        assert_eq!(RetryBackoff::delay(&LINEAR.scale(-1.0), 1), Duration::ZERO);
+        assert_eq!(
+            RetryBackoff::delay(&LINEAR.scale(f64::NAN), 1),
+            Duration::ZERO
+        );
+        assert_eq!(RetryBackoff::name(&backoff), "Scale");
+    }
+
+    #[test]
+    fn test_offset() {
+        let backoff = LINEAR.add(Duration::from_millis(200));
+
+        for (attempts, millis) in &[(1, 1200), (2, 2200), (3, 3200)] {
+            assert_eq!(
+                RetryBackoff::delay(&backoff, *attempts),
+                Duration::from_millis(*millis)
+            );
+        }
+        assert_eq!(
+            RetryBackoff::delay(&LINEAR.add(Duration::MAX), 1),
+            Duration::MAX
+        );
+        assert_eq!(RetryBackoff::name(&backoff), "Offset");
+    }
+
+    #[test]
+    fn test_chained() {
+        let backoff = LINEAR
+            .scale(2.0)
+            .add(Duration::from_secs(1))
+            .max_delay(Duration::from_secs(6))
+            .min_delay(Duration::from_secs(4));
+
+        for (attempts, secs) in &[(1, 4), (2, 5), (3, 6), (4, 6)] {
+            assert_eq!(
+                RetryBackoff::delay(&backoff, *attempts),
+                Duration::from_secs(*secs)
+            );
+        }
+    }
+
+    #[test]
+    fn test_chain() {
+        use crate::backoffs::{ConstantBackoff, ExponentialBackoff};
+
+        // three immediate retries, then exponential
+        let backoff = ConstantBackoff::new(Duration::ZERO).chain(
+            3,
+            ExponentialBackoff::new(Duration::from_secs(1), 2, Duration::from_secs(60)),
+        );
+
+        for (attempts, secs) in &[(0, 0), (1, 0), (2, 0), (3, 0), (4, 1), (5, 2), (6, 4)] {
+            assert_eq!(
+                RetryBackoff::delay(&backoff, *attempts),
+                Duration::from_secs(*secs)
+            );
+        }
+        assert_eq!(
+            RetryBackoff::delay(&backoff, usize::MAX),
+            Duration::from_secs(60)
+        );
+        assert_eq!(RetryBackoff::name(&backoff), "Chain");
+
+        // nested, each segment sees its own attempts starting from 1
+        let backoff = LINEAR
+            .chain(2, LINEAR.scale(10.0))
+            .chain(4, LINEAR.scale(100.0));
+
+        for (attempts, secs) in &[(1, 1), (2, 2), (3, 10), (4, 20), (5, 100), (6, 200)] {
+            assert_eq!(
+                RetryBackoff::delay(&backoff, *attempts),
+                Duration::from_secs(*secs)
+            );
+        }
+
+        let backoff = LINEAR.chain(0, LINEAR.scale(10.0));
+        assert_eq!(RetryBackoff::delay(&backoff, 1), Duration::from_secs(10));
+    }
+
+    #[cfg(target_has_atomic = "64")]
+    #[test]
+    fn test_try_delay_bounds() {
+        use crate::{
+            backoffs::{ConstantBackoff, Jitter},
+            random::SeededRandom,
+        };
+
+        let backoff = Jitter::equal(LINEAR, SeededRandom::new(1))
+            .scale(2.0)
+            .add(Duration::from_secs(1))
+            .max_delay(Duration::from_secs(8))
+            .min_delay(Duration::from_secs(6));
+        // (2, 4) -> (4, 8) -> (5, 9) -> (5, 8) -> (6, 8)
+        assert_eq!(
+            RetryBackoff::try_delay_bounds(&backoff, 4),
+            Some((Duration::from_secs(6), Duration::from_secs(8)))
+        );
+
+        let backoff = ConstantBackoff::new(Duration::ZERO)
+            .chain(1, Jitter::equal(LINEAR, SeededRandom::new(1)));
+        assert_eq!(
+            RetryBackoff::try_delay_bounds(&backoff, 1),
+            Some((Duration::ZERO, Duration::ZERO))
+        );
+        assert_eq!(
+            RetryBackoff::try_delay_bounds(&backoff, 5),
+            Some((Duration::from_secs(2), Duration::from_secs(4)))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_debug() {
+        let backoff = LINEAR.max_delay(Duration::from_secs(10));
+        assert_eq!(
+            alloc::format!("{backoff:?}"),
+            r#"Cap { inner: "Linear", max: 10s }"#
+        );
+
+        let backoff = LINEAR.scale(2.0).min_delay(Duration::from_millis(200));
+        assert_eq!(
+            alloc::format!("{backoff:?}"),
+            r#"Floor { inner: "Scale", min: 200ms }"#
+        );
+        assert_eq!(
+            alloc::format!("{:?}", backoff.inner()),
+            r#"Scale { inner: "Linear", factor: 2.0 }"#
+        );
+
+        let backoff = LINEAR.chain(3, LINEAR.scale(2.0));
+        assert_eq!(
+            alloc::format!("{backoff:?}"),
+            r#"Chain { first: "Linear", first_attempts: 3, then: "Scale" }"#
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use core::{fmt, time::Duration};
+
+use crate::retry_backoff::RetryBackoff;
+
+//
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Backoff {
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub delay: Duration,
+}
+
+impl Backoff {
+    pub const fn new(delay: Duration) -> Self {
+        Self { delay }
+    }
+}
+
+//
+impl RetryBackoff for Backoff {
+    fn delay(&self, _attempts: usize) -> Duration {
+        self.delay
+    }
+
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        params("delay", &self.delay);
+    }
+
+    fn name(&self) -> &str {
+        "Constant"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_impl_retry_backoff() {
+        const BACKOFF: Backoff = Backoff::new(Duration::from_secs(1));
+
+        for attempts in [0, 1, 2, 10, usize::MAX] {
+            assert_eq!(
+                RetryBackoff::delay(&BACKOFF, attempts),
+                Duration::from_secs(1)
+            );
+        }
+        assert_eq!(RetryBackoff::name(&BACKOFF), "Constant");
+    }
+}
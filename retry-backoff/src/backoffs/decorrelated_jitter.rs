@@ -0,0 +1,151 @@
+//! [Ref Exponential Backoff And Jitter](https://aws.amazon.com/blogs/architecture/exponential-backoff-and-jitter/)
+//!
+//! `sleep = min(cap, random_between(base, prev_sleep * 3))`
+
+use core::{fmt, time::Duration};
+
+use crate::{
+    random::RandomSource,
+    retry_backoff::{BackoffState, RetryBackoff},
+};
+
+// Enough for `base * 3^n` to cross any Duration, starting from 1ns.
+const MAX_SIMULATED_STEPS: usize = 64;
+
+//
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Backoff<R> {
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub base: Duration,
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub cap: Duration,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    rng: R,
+}
+
+impl<R> Backoff<R> {
+    pub fn new(base: Duration, cap: Duration, rng: R) -> Self {
+        Self { base, cap, rng }
+    }
+}
+
+impl<R> Backoff<R>
+where
+    R: RandomSource,
+{
+    pub fn next_delay(&self, prev_delay: Duration) -> Duration {
+        self.rng
+            .duration_between(self.base, prev_delay.saturating_mul(3))
+            .min(self.cap)
+    }
+}
+
+//
+impl<R> RetryBackoff for Backoff<R>
+where
+    R: RandomSource,
+{
+    /// Without state, the chain is simulated from base.
+    fn delay(&self, attempts: usize) -> Duration {
+        (0..attempts.clamp(1, MAX_SIMULATED_STEPS)).fold(self.base, |prev, _| self.next_delay(prev))
+    }
+
+    fn delay_with_state(&self, attempts: usize, state: &BackoffState) -> Duration {
+        match state.prev_delay {
+            Some(prev_delay) => self.next_delay(prev_delay),
+            None => self.delay(attempts),
+        }
+    }
+
+    /// the bounds of the stateless chain
+    fn try_delay_bounds(&self, attempts: usize) -> Option<(Duration, Duration)> {
+        let max = (0..attempts.clamp(1, MAX_SIMULATED_STEPS)).fold(self.base, |prev, _| {
+            prev.saturating_mul(3).max(self.base).min(self.cap)
+        });
+        Some((self.base.min(self.cap), max))
+    }
+
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        params("base", &self.base);
+        params("cap", &self.cap);
+    }
+
+    fn name(&self) -> &str {
+        "DecorrelatedJitter"
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::random::SeededRandom;
+
+    #[test]
+    fn test_delay_with_state() {
+        let base = Duration::from_millis(100);
+        let cap = Duration::from_secs(10);
+        let backoff = Backoff::new(base, cap, SeededRandom::new(1));
+
+        let mut state = BackoffState::new();
+        let mut grown = false;
+        for attempts in 1..=100 {
+            let prev_delay = state.prev_delay.unwrap_or(base);
+            let dur = RetryBackoff::delay_with_state(&backoff, attempts, &state);
+            assert!(dur >= base && dur <= cap, "{attempts} {dur:?}");
+            assert!(dur <= prev_delay * 3, "{attempts} {dur:?}");
+            grown |= dur > base * 3;
+            state.record(dur);
+        }
+        assert!(grown);
+
+        assert_eq!(RetryBackoff::name(&backoff), "DecorrelatedJitter");
+    }
+
+    #[test]
+    fn test_delay() {
+        let base = Duration::from_millis(100);
+        let cap = Duration::from_secs(10);
+        let backoff = Backoff::new(base, cap, SeededRandom::new(1));
+
+        let dur = RetryBackoff::delay(&backoff, 1);
+        assert!(dur >= base && dur <= base * 3);
+
+        for attempts in [0, 2, 10, usize::MAX] {
+            let dur = RetryBackoff::delay(&backoff, attempts);
+            assert!(dur >= base && dur <= cap);
+        }
+
+        let backoff = Backoff::new(base, base, SeededRandom::new(1));
+        assert_eq!(RetryBackoff::delay(&backoff, 5), base);
+
+        let backoff = Backoff::new(Duration::from_secs(20), cap, SeededRandom::new(1));
+        assert_eq!(RetryBackoff::delay(&backoff, 1), cap);
+    }
+
+    #[test]
+    fn test_try_delay_bounds() {
+        let base = Duration::from_millis(100);
+        let cap = Duration::from_secs(10);
+        let backoff = Backoff::new(base, cap, SeededRandom::new(1));
+
+        for (attempts, max_millis) in [(0, 300), (1, 300), (2, 900), (3, 2700), (5, 10000)] {
+            assert_eq!(
+                RetryBackoff::try_delay_bounds(&backoff, attempts),
+                Some((base, Duration::from_millis(max_millis)))
+            );
+        }
+        assert_eq!(
+            RetryBackoff::try_delay_bounds(&backoff, usize::MAX),
+            Some((base, cap))
+        );
+
+        for attempts in 1..=10 {
+            let (min, max) = RetryBackoff::try_delay_bounds(&backoff, attempts).unwrap();
+            let dur = RetryBackoff::delay(&backoff, attempts);
+            assert!(dur >= min && dur <= max, "{attempts} {dur:?}");
+        }
+    }
+}
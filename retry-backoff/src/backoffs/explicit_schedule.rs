@@ -0,0 +1,180 @@
+//! A finite schedule, exhausted after its last delay.
+
+use core::{fmt, time::Duration};
+
+use crate::retry_backoff::{BackoffState, RetryBackoff};
+
+//
+/// `S` is `[Duration; N]`, `&'static [Duration]`, `Vec<Duration>` and so on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Backoff<S> {
+    delays: S,
+}
+
+impl<S> Backoff<S> {
+    pub const fn new(delays: S) -> Self {
+        Self { delays }
+    }
+}
+
+impl<S> Backoff<S>
+where
+    S: AsRef<[Duration]>,
+{
+    pub fn delays(&self) -> &[Duration] {
+        self.delays.as_ref()
+    }
+
+    /// attempts 0 is treated as 1
+    pub fn get(&self, attempts: usize) -> Option<Duration> {
+        self.delays().get(attempts.max(1) - 1).copied()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<S> serde::Serialize for Backoff<S>
+where
+    S: AsRef<[Duration]>,
+{
+    fn serialize<SE>(&self, serializer: SE) -> Result<SE::Ok, SE::Error>
+    where
+        SE: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct as _;
+
+        let mut state = serializer.serialize_struct("Backoff", 1)?;
+        state.serialize_field(
+            "delays",
+            &self
+                .delays()
+                .iter()
+                .map(|delay| humantime_serde::Serde::from(*delay))
+                .collect::<alloc::vec::Vec<_>>(),
+        )?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Backoff<alloc::vec::Vec<Duration>> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        struct Repr {
+            delays: alloc::vec::Vec<humantime_serde::Serde<Duration>>,
+        }
+
+        let repr = Repr::deserialize(deserializer)?;
+        Ok(Self::new(
+            repr.delays
+                .into_iter()
+                .map(humantime_serde::Serde::into_inner)
+                .collect(),
+        ))
+    }
+}
+
+//
+impl<S> RetryBackoff for Backoff<S>
+where
+    S: AsRef<[Duration]>,
+{
+    /// Past the end, the last delay.
+    fn delay(&self, attempts: usize) -> Duration {
+        self.get(attempts)
+            .or_else(|| self.delays().last().copied())
+            .unwrap_or_default()
+    }
+
+    fn try_delay_with_state(&self, attempts: usize, _state: &BackoffState) -> Option<Duration> {
+        self.get(attempts)
+    }
+
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        params("delays", &self.delays());
+    }
+
+    fn name(&self) -> &str {
+        "ExplicitSchedule"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static BACKOFF: Backoff<[Duration; 3]> = Backoff::new([
+        Duration::from_secs(1),
+        Duration::from_secs(5),
+        Duration::from_secs(30),
+    ]);
+
+    #[test]
+    fn test_impl_retry_backoff() {
+        for (attempts, secs) in &[
+            (0, Some(1)),
+            (1, Some(1)),
+            (2, Some(5)),
+            (3, Some(30)),
+            (4, None),
+            (usize::MAX, None),
+        ] {
+            assert_eq!(
+                RetryBackoff::try_delay(&BACKOFF, *attempts),
+                secs.map(Duration::from_secs)
+            );
+        }
+        for (attempts, secs) in &[(1, 1), (3, 30), (4, 30), (usize::MAX, 30)] {
+            assert_eq!(
+                RetryBackoff::delay(&BACKOFF, *attempts),
+                Duration::from_secs(*secs)
+            );
+        }
+        assert_eq!(RetryBackoff::name(&BACKOFF), "ExplicitSchedule");
+    }
+
+    #[test]
+    fn test_from_slice() {
+        static DELAYS: &[Duration] = &[Duration::from_millis(100), Duration::from_millis(200)];
+        let backoff = Backoff::new(DELAYS);
+
+        assert_eq!(backoff.delays(), DELAYS);
+        assert_eq!(
+            RetryBackoff::try_delay(&backoff, 2),
+            Some(Duration::from_millis(200))
+        );
+        assert_eq!(RetryBackoff::try_delay(&backoff, 3), None);
+
+        let backoff = Backoff::new(&[][..]);
+        assert_eq!(RetryBackoff::delay(&backoff, 1), Duration::ZERO);
+        assert_eq!(RetryBackoff::try_delay(&backoff, 1), None);
+    }
+
+    #[test]
+    fn test_wrapped() {
+        use crate::RetryBackoffExt as _;
+
+        let backoff = BACKOFF.clone().max_delay(Duration::from_secs(10));
+        assert_eq!(
+            RetryBackoff::try_delay(&backoff, 3),
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(RetryBackoff::try_delay(&backoff, 4), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let json = r#"{"delays":["1s","5s","30s"]}"#;
+
+        assert_eq!(serde_json::to_string(&BACKOFF).unwrap(), json);
+        assert_eq!(
+            serde_json::from_str::<Backoff<alloc::vec::Vec<Duration>>>(json)
+                .unwrap()
+                .delays(),
+            BACKOFF.delays()
+        );
+    }
+}
@@ -0,0 +1,227 @@
+//! `initial * multiplier^(attempts - 1)`, capped at max.
+//!
+//! Integer/fixed-point math only, works without std and alloc.
+
+use core::{fmt, time::Duration};
+
+use crate::{retry_backoff::RetryBackoff, utils::nanos_to_duration};
+
+/// multiplier_milli of 1.0
+pub const MULTIPLIER_MILLI_ONE: u32 = 1000;
+
+//
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Backoff {
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub initial: Duration,
+    /// fixed-point multiplier in thousandths, 2500 is 2.5
+    /// values below 1000 are treated as 1000
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "multiplier", with = "multiplier_milli_as_f64")
+    )]
+    pub multiplier_milli: u32,
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub max: Duration,
+}
+
+impl Backoff {
+    pub const fn new(initial: Duration, multiplier: u32, max: Duration) -> Self {
+        Self::with_multiplier_milli(
+            initial,
+            multiplier.saturating_mul(MULTIPLIER_MILLI_ONE),
+            max,
+        )
+    }
+
+    pub const fn with_multiplier_milli(
+        initial: Duration,
+        multiplier_milli: u32,
+        max: Duration,
+    ) -> Self {
+        Self {
+            initial,
+            multiplier_milli,
+            max,
+        }
+    }
+
+    pub fn delay(&self, attempts: usize) -> Duration {
+        let max = self.max.as_nanos();
+        let multiplier_milli = self.multiplier_milli.max(MULTIPLIER_MILLI_ONE) as u128;
+
+        let mut nanos = self.initial.as_nanos().min(max);
+        for _ in 1..attempts {
+            if nanos >= max {
+                break;
+            }
+
+            let next = nanos.saturating_mul(multiplier_milli) / MULTIPLIER_MILLI_ONE as u128;
+            if next == nanos {
+                break;
+            }
+            nanos = next.min(max);
+        }
+
+        nanos_to_duration(nanos)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod multiplier_milli_as_f64 {
+    use serde::{Deserialize as _, Deserializer, Serializer, de::Error as _};
+
+    use super::MULTIPLIER_MILLI_ONE;
+
+    pub(super) fn serialize<S>(multiplier_milli: &u32, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_f64(*multiplier_milli as f64 / MULTIPLIER_MILLI_ONE as f64)
+    }
+
+    pub(super) fn deserialize<'de, D>(deserializer: D) -> Result<u32, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let multiplier = f64::deserialize(deserializer)?;
+        let milli = (multiplier * MULTIPLIER_MILLI_ONE as f64).round();
+        if !milli.is_finite() || milli < 0.0 || milli > u32::MAX as f64 {
+            return Err(D::Error::custom(format_args!(
+                "invalid multiplier {multiplier}, expected 0 to {}",
+                u32::MAX as f64 / MULTIPLIER_MILLI_ONE as f64
+            )));
+        }
+        Ok(milli as u32)
+    }
+}
+
+//
+impl RetryBackoff for Backoff {
+    fn delay(&self, attempts: usize) -> Duration {
+        Self::delay(self, attempts)
+    }
+
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        params("initial", &self.initial);
+        params(
+            "multiplier",
+            &(self.multiplier_milli as f64 / MULTIPLIER_MILLI_ONE as f64),
+        );
+        params("max", &self.max);
+    }
+
+    fn name(&self) -> &str {
+        "Exponential"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay() {
+        let backoff = Backoff::new(Duration::from_secs(1), 2, Duration::from_secs(60));
+
+        for (attempts, secs) in &[
+            (1, 1),
+            (2, 2),
+            (3, 4),
+            (4, 8),
+            (5, 16),
+            (6, 32),
+            (7, 60),
+            (8, 60),
+            (100, 60),
+            (usize::MAX, 60),
+        ] {
+            assert_eq!(backoff.delay(*attempts), Duration::from_secs(*secs));
+        }
+        assert_eq!(backoff.delay(0), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_delay_with_multiplier_milli() {
+        let backoff = Backoff::with_multiplier_milli(
+            Duration::from_millis(100),
+            1500,
+            Duration::from_secs(1),
+        );
+
+        for (attempts, millis) in &[(1, 100), (2, 150), (3, 225), (4, 337), (5, 506), (7, 1000)] {
+            assert!(
+                backoff
+                    .delay(*attempts)
+                    .abs_diff(Duration::from_millis(*millis))
+                    < Duration::from_millis(1)
+            );
+        }
+
+        // below 1.0
+        let backoff =
+            Backoff::with_multiplier_milli(Duration::from_secs(1), 500, Duration::from_secs(60));
+        assert_eq!(backoff.delay(10), Duration::from_secs(1));
+
+        // no progress in fixed-point
+        let backoff =
+            Backoff::with_multiplier_milli(Duration::from_nanos(1), 1001, Duration::from_secs(60));
+        assert_eq!(backoff.delay(usize::MAX), Duration::from_nanos(1));
+    }
+
+    #[test]
+    fn test_delay_without_overflow() {
+        let backoff = Backoff::new(Duration::MAX, u32::MAX, Duration::MAX);
+        assert_eq!(backoff.delay(usize::MAX), Duration::MAX);
+
+        let backoff = Backoff::new(Duration::from_secs(1), u32::MAX, Duration::MAX);
+        assert_eq!(backoff.delay(1), Duration::from_secs(1));
+        assert_eq!(backoff.delay(usize::MAX), Duration::MAX);
+
+        let backoff = Backoff::new(Duration::from_secs(10), 2, Duration::from_secs(5));
+        assert_eq!(backoff.delay(1), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_impl_retry_backoff() {
+        const BACKOFF: Backoff =
+            Backoff::new(Duration::from_millis(100), 2, Duration::from_secs(1));
+
+        assert_eq!(RetryBackoff::delay(&BACKOFF, 1), Duration::from_millis(100));
+        assert_eq!(RetryBackoff::delay(&BACKOFF, 4), Duration::from_millis(800));
+        assert_eq!(RetryBackoff::delay(&BACKOFF, 5), Duration::from_secs(1));
+        assert_eq!(RetryBackoff::name(&BACKOFF), "Exponential");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let backoff = Backoff::with_multiplier_milli(
+            Duration::from_millis(100),
+            1500,
+            Duration::from_secs(30),
+        );
+        let json = r#"{"initial":"100ms","multiplier":1.5,"max":"30s"}"#;
+
+        assert_eq!(serde_json::to_string(&backoff).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Backoff>(json).unwrap(), backoff);
+
+        for multiplier in ["-1.0", "1e10"] {
+            let json = format!(r#"{{"initial":"100ms","multiplier":{multiplier},"max":"30s"}}"#);
+            let err = serde_json::from_str::<Backoff>(&json).unwrap_err();
+            assert!(err.to_string().contains("invalid multiplier"), "{err}");
+        }
+
+        // not representable in JSON
+        use serde::de::{IntoDeserializer, value::Error};
+        for multiplier in [f64::NAN, f64::INFINITY] {
+            assert!(
+                super::multiplier_milli_as_f64::deserialize(
+                    IntoDeserializer::<Error>::into_deserializer(multiplier)
+                )
+                .is_err()
+            );
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! `initial * fibonacci(attempts)`, capped at max. The sequence starts 1, 1, 2, 3, 5.
+
+use core::{fmt, time::Duration};
+
+use crate::{retry_backoff::RetryBackoff, utils::nanos_to_duration};
+
+//
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Backoff {
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub initial: Duration,
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub max: Duration,
+}
+
+impl Backoff {
+    pub const fn new(initial: Duration, max: Duration) -> Self {
+        Self { initial, max }
+    }
+
+    pub fn delay(&self, attempts: usize) -> Duration {
+        let max = self.max.as_nanos();
+
+        let (mut prev, mut curr) = (0_u128, self.initial.as_nanos());
+        for _ in 1..attempts {
+            if curr >= max || curr == 0 {
+                break;
+            }
+            (prev, curr) = (curr, prev.saturating_add(curr));
+        }
+
+        nanos_to_duration(curr.min(max))
+    }
+}
+
+//
+impl RetryBackoff for Backoff {
+    fn delay(&self, attempts: usize) -> Duration {
+        Self::delay(self, attempts)
+    }
+
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        params("initial", &self.initial);
+        params("max", &self.max);
+    }
+
+    fn name(&self) -> &str {
+        "Fibonacci"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay() {
+        let backoff = Backoff::new(Duration::from_secs(1), Duration::from_secs(30));
+
+        for (attempts, secs) in &[
+            (0, 1),
+            (1, 1),
+            (2, 1),
+            (3, 2),
+            (4, 3),
+            (5, 5),
+            (6, 8),
+            (7, 13),
+            (8, 21),
+            (9, 30),
+            (100, 30),
+            (usize::MAX, 30),
+        ] {
+            assert_eq!(backoff.delay(*attempts), Duration::from_secs(*secs));
+        }
+
+        let backoff = Backoff::new(Duration::ZERO, Duration::from_secs(30));
+        assert_eq!(backoff.delay(usize::MAX), Duration::ZERO);
+
+        let backoff = Backoff::new(Duration::MAX, Duration::MAX);
+        assert_eq!(backoff.delay(usize::MAX), Duration::MAX);
+    }
+
+    #[test]
+    fn test_impl_retry_backoff() {
+        const BACKOFF: Backoff = Backoff::new(Duration::from_millis(100), Duration::from_secs(1));
+
+        assert_eq!(RetryBackoff::delay(&BACKOFF, 4), Duration::from_millis(300));
+        assert_eq!(RetryBackoff::name(&BACKOFF), "Fibonacci");
+    }
+}
@@ -1,9 +1,19 @@
+//! [Retry steps](https://cloud.google.com/workflows/docs/reference/syntax/retrying)
+//!
+//! `initial_delay * multiplier^(attempts - 1)`, capped at max_delay.
+
+use core::time::Duration;
+
+use crate::utils::secs_f64_to_duration;
+
 //
+/// Fields and `new` take f64 since 0.3, they were f32 before.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Backoff {
-    pub initial_delay_secs: f32,
-    pub max_delay_secs: f32,
-    pub multiplier: f32,
+    pub initial_delay_secs: f64,
+    pub max_delay_secs: f64,
+    pub multiplier: f64,
 }
 
 impl Default for Backoff {
@@ -18,7 +28,7 @@ pub fn default_backoff() -> Backoff {
 }
 
 impl Backoff {
-    pub fn new(initial_delay_secs: f32, max_delay_secs: f32, multiplier: f32) -> Self {
+    pub fn new(initial_delay_secs: f64, max_delay_secs: f64, multiplier: f64) -> Self {
         Self {
             initial_delay_secs,
             max_delay_secs,
@@ -26,28 +36,45 @@ impl Backoff {
         }
     }
 
+    pub fn from_durations(initial_delay: Duration, max_delay: Duration, multiplier: f64) -> Self {
+        Self::new(
+            initial_delay.as_secs_f64(),
+            max_delay.as_secs_f64(),
+            multiplier,
+        )
+    }
+
+    pub fn initial_delay(&self) -> Duration {
+        secs_f64_to_duration(self.initial_delay_secs)
+    }
+
+    pub fn max_delay(&self) -> Duration {
+        secs_f64_to_duration(self.max_delay_secs)
+    }
+
+    /// attempts 0 is treated as 1
     #[cfg(feature = "std")]
-    pub fn delay(&self, attempts: usize) -> core::time::Duration {
-        use core::{cmp::min, time::Duration};
-
-        match attempts {
-            0 => unreachable!(),
-            1 => Duration::from_millis((self.initial_delay_secs * 1000.0).round() as u64),
-            n => Duration::from_millis(min(
-                (self.multiplier.powi((n - 1) as i32) * 1000.0).round() as u64,
-                (self.max_delay_secs * 1000.0).round() as u64,
-            )),
-        }
+    pub fn delay(&self, attempts: usize) -> Duration {
+        let exponent = i32::try_from(attempts.saturating_sub(1)).unwrap_or(i32::MAX);
+
+        secs_f64_to_duration(self.initial_delay_secs * self.multiplier.powi(exponent))
+            .min(self.max_delay())
     }
 }
 
 //
 #[cfg(feature = "std")]
 impl crate::retry_backoff::RetryBackoff for Backoff {
-    fn delay(&self, attempts: usize) -> core::time::Duration {
+    fn delay(&self, attempts: usize) -> Duration {
         Self::delay(self, attempts)
     }
 
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn core::fmt::Debug)) {
+        params("initial", &self.initial_delay());
+        params("multiplier", &self.multiplier);
+        params("max", &self.max_delay());
+    }
+
     fn name(&self) -> &str {
         "GoogleCloudWorkflows"
     }
@@ -67,11 +94,30 @@ mod tests {
         assert_eq!(Backoff::default(), default_backoff());
     }
 
+    #[test]
+    fn test_from_durations() {
+        let backoff =
+            Backoff::from_durations(Duration::from_micros(500), Duration::from_secs(60), 2.0);
+        assert_eq!(backoff.initial_delay(), Duration::from_micros(500));
+        assert_eq!(backoff.max_delay(), Duration::from_secs(60));
+
+        assert_eq!(
+            Backoff::new(-1.0, f64::NAN, 2.0).initial_delay(),
+            Duration::ZERO
+        );
+        assert_eq!(
+            Backoff::new(-1.0, f64::NAN, 2.0).max_delay(),
+            Duration::ZERO
+        );
+        assert_eq!(
+            Backoff::new(1.0, f64::INFINITY, 2.0).max_delay(),
+            Duration::MAX
+        );
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn test_delay() {
-        use core::time::Duration;
-
         // Ref https://cloud.google.com/workflows/docs/reference/syntax/retrying#try-retry
         let backoff = Backoff::new(1.0, 60.0, 2.0);
 
@@ -89,13 +135,29 @@ mod tests {
         ] {
             assert_eq!(backoff.delay(*attempts), Duration::from_secs(*secs));
         }
+
+        //
+        let backoff = Backoff::new(5.0, 60.0, 2.0);
+
+        for (attempts, secs) in &[(0, 5), (1, 5), (2, 10), (3, 20), (4, 40), (5, 60), (6, 60)] {
+            assert_eq!(backoff.delay(*attempts), Duration::from_secs(*secs));
+        }
+        assert_eq!(backoff.delay(usize::MAX), Duration::from_secs(60));
+
+        //
+        let backoff =
+            Backoff::from_durations(Duration::from_micros(500), Duration::from_secs(1), 1.5);
+        assert_eq!(backoff.delay(1), Duration::from_micros(500));
+        assert_eq!(backoff.delay(2), Duration::from_micros(750));
+
+        //
+        let backoff = Backoff::new(1.0, f64::INFINITY, 2.0);
+        assert_eq!(backoff.delay(usize::MAX), Duration::MAX);
     }
 
     #[cfg(feature = "std")]
     #[test]
     fn test_impl_retry_backoff() {
-        use core::time::Duration;
-
         use crate::retry_backoff::RetryBackoff;
 
         // Ref https://cloud.google.com/workflows/docs/reference/syntax/retrying#try-retry
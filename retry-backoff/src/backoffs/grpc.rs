@@ -0,0 +1,272 @@
+//! [gRPC Connection Backoff Protocol](https://github.com/grpc/grpc/blob/master/doc/connection-backoff.md)
+//!
+//! ```text
+//! ConnectWithBackoff()
+//!   current_backoff = INITIAL_BACKOFF
+//!   current_deadline = now() + INITIAL_BACKOFF
+//!   while (TryConnect(Max(current_deadline, now() + MIN_CONNECT_TIMEOUT)) != SUCCESS)
+//!     SleepUntil(current_deadline)
+//!     current_backoff = Min(current_backoff * MULTIPLIER, MAX_BACKOFF)
+//!     current_deadline = now() + current_backoff +
+//!       UniformRandom(-JITTER * current_backoff, JITTER * current_backoff)
+//! ```
+//!
+//! The deadline counts from the start of the attempt, so the measured attempt duration in
+//! [`BackoffState`](crate::retry_backoff::BackoffState) is subtracted from the delay.
+//!
+//! `Max(current_deadline, now() + MIN_CONNECT_TIMEOUT)` is [`Backoff::connect_timeout`], reported as
+//! the attempt timeout, see `async_retry::retry_with_policy_timeout`.
+
+use core::{fmt, time::Duration};
+
+use crate::{
+    backoffs::JitterMode,
+    random::RandomSource,
+    retry_backoff::{BackoffState, RetryBackoff},
+    utils::secs_f64_to_duration,
+};
+
+pub const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+pub const MULTIPLIER: f64 = 1.6;
+pub const JITTER: f64 = 0.2;
+pub const MAX_BACKOFF: Duration = Duration::from_secs(120);
+pub const MIN_CONNECT_TIMEOUT: Duration = Duration::from_secs(20);
+
+//
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Backoff<R> {
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub initial_backoff: Duration,
+    pub multiplier: f64,
+    pub jitter: f64,
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub max_backoff: Duration,
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub min_connect_timeout: Duration,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    rng: R,
+}
+
+impl<R> Backoff<R> {
+    /// the spec defaults
+    pub const fn new(rng: R) -> Self {
+        Self {
+            initial_backoff: INITIAL_BACKOFF,
+            multiplier: MULTIPLIER,
+            jitter: JITTER,
+            max_backoff: MAX_BACKOFF,
+            min_connect_timeout: MIN_CONNECT_TIMEOUT,
+            rng,
+        }
+    }
+
+    /// `current_backoff` before jitter, attempts 0 is treated as 1
+    pub fn current_backoff(&self, attempts: usize) -> Duration {
+        let exponent = i32::try_from(attempts.saturating_sub(1)).unwrap_or(i32::MAX);
+        if exponent == 0 {
+            return self.initial_backoff;
+        }
+
+        secs_f64_to_duration(self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent))
+            .min(self.max_backoff)
+    }
+
+    /// `Max(current_deadline, now() + MIN_CONNECT_TIMEOUT)` for attempts, counted from 1 for the first connect
+    /// current_deadline is taken at its largest jitter, so no attempt is cut short
+    pub fn connect_timeout(&self, attempts: usize) -> Duration {
+        let (_, current_deadline) = self
+            .jitter_mode(attempts)
+            .bounds(self.current_backoff(attempts));
+        current_deadline.max(self.min_connect_timeout)
+    }
+
+    /// the first deadline is not jittered
+    fn jitter_mode(&self, attempts: usize) -> JitterMode {
+        if attempts <= 1 {
+            JitterMode::Proportional(0.0)
+        } else {
+            JitterMode::Proportional(self.jitter)
+        }
+    }
+}
+
+//
+impl<R> RetryBackoff for Backoff<R>
+where
+    R: RandomSource,
+{
+    fn delay(&self, attempts: usize) -> Duration {
+        self.jitter_mode(attempts)
+            .apply(self.current_backoff(attempts), &self.rng)
+    }
+
+    /// `SleepUntil(current_deadline)`
+    fn delay_with_state(&self, attempts: usize, state: &BackoffState) -> Duration {
+        self.delay(attempts)
+            .saturating_sub(state.last_attempt_elapsed.unwrap_or_default())
+    }
+
+    fn try_delay_bounds(&self, attempts: usize) -> Option<(Duration, Duration)> {
+        Some(
+            self.jitter_mode(attempts)
+                .bounds(self.current_backoff(attempts)),
+        )
+    }
+
+    fn attempt_timeout(&self, attempts: usize) -> Option<Duration> {
+        Some(self.connect_timeout(attempts))
+    }
+
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        params("initial_backoff", &self.initial_backoff);
+        params("multiplier", &self.multiplier);
+        params("jitter", &self.jitter);
+        params("max_backoff", &self.max_backoff);
+        params("min_connect_timeout", &self.min_connect_timeout);
+    }
+
+    fn name(&self) -> &str {
+        "Grpc"
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::random::SeededRandom;
+
+    #[test]
+    fn test_new() {
+        let backoff = Backoff::new(SeededRandom::new(1));
+        assert_eq!(backoff.initial_backoff, Duration::from_secs(1));
+        assert_eq!(backoff.multiplier, 1.6);
+        assert_eq!(backoff.jitter, 0.2);
+        assert_eq!(backoff.max_backoff, Duration::from_secs(120));
+        assert_eq!(backoff.min_connect_timeout, Duration::from_secs(20));
+    }
+
+    #[test]
+    fn test_current_backoff() {
+        let backoff = Backoff::new(SeededRandom::new(1));
+
+        for (attempts, millis) in &[
+            (0, 1000),
+            (1, 1000),
+            (2, 1600),
+            (3, 2560),
+            (4, 4096),
+            (11, 109_951),
+            (12, 120_000),
+            (usize::MAX, 120_000),
+        ] {
+            let dur = backoff.current_backoff(*attempts);
+            assert_eq!(dur.as_millis(), *millis as u128, "{attempts}");
+        }
+    }
+
+    #[test]
+    fn test_impl_retry_backoff() {
+        let backoff = Backoff::new(SeededRandom::new(1));
+
+        for _ in 0..100 {
+            assert_eq!(RetryBackoff::delay(&backoff, 1), INITIAL_BACKOFF);
+        }
+        assert_eq!(
+            RetryBackoff::try_delay_bounds(&backoff, 1),
+            Some((INITIAL_BACKOFF, INITIAL_BACKOFF))
+        );
+
+        // UniformRandom(-JITTER * current_backoff, JITTER * current_backoff)
+        assert_eq!(
+            RetryBackoff::try_delay_bounds(&backoff, 2),
+            Some((Duration::from_millis(1280), Duration::from_millis(1920)))
+        );
+        assert_eq!(
+            RetryBackoff::try_delay_bounds(&backoff, 100),
+            Some((Duration::from_secs(96), Duration::from_secs(144)))
+        );
+        for attempts in 2..=20 {
+            let (low, high) = RetryBackoff::try_delay_bounds(&backoff, attempts).unwrap();
+            let dur = RetryBackoff::delay(&backoff, attempts);
+            assert!(dur >= low && dur <= high, "{attempts} {dur:?}");
+        }
+
+        assert_eq!(RetryBackoff::name(&backoff), "Grpc");
+    }
+
+    #[test]
+    fn test_connect_timeout() {
+        let backoff = Backoff::new(SeededRandom::new(1));
+
+        // MIN_CONNECT_TIMEOUT until current_deadline grows past it
+        for (attempts, millis) in &[
+            (0, 20_000),
+            (1, 20_000),
+            (6, 20_000),
+            // 1.6^6 s = 16.78s, 20.13s at +20% jitter
+            (7, 20_132),
+            (8, 32_212),
+            (100, 144_000),
+        ] {
+            let dur = backoff.connect_timeout(*attempts);
+            assert_eq!(dur.as_millis(), *millis as u128, "{attempts}");
+            assert_eq!(
+                RetryBackoff::attempt_timeout(&backoff, *attempts),
+                Some(dur)
+            );
+        }
+    }
+
+    #[test]
+    fn test_connect_with_backoff() {
+        // the pseudo-code with a virtual clock and no jitter
+        let mut backoff = Backoff::new(SeededRandom::new(1));
+        backoff.jitter = 0.0;
+        let try_connect_durs = [
+            Duration::from_millis(300),
+            Duration::from_secs(25),
+            Duration::ZERO,
+            Duration::from_secs(3),
+        ];
+
+        let mut now = Duration::ZERO;
+        let mut current_backoff = INITIAL_BACKOFF;
+        let mut current_deadline = now + INITIAL_BACKOFF;
+        let mut state = BackoffState::new();
+        for (i, try_connect_dur) in try_connect_durs.iter().enumerate() {
+            let attempt_started_at = now;
+            let connect_deadline = current_deadline.max(now + MIN_CONNECT_TIMEOUT);
+            now = (now + *try_connect_dur).min(connect_deadline);
+            let elapsed = now - attempt_started_at;
+
+            let sleep = current_deadline.saturating_sub(now);
+            now += sleep;
+            current_backoff =
+                secs_f64_to_duration(current_backoff.as_secs_f64() * MULTIPLIER).min(MAX_BACKOFF);
+            current_deadline = now + current_backoff;
+
+            state.record_attempt_elapsed(elapsed);
+            let dur = RetryBackoff::delay_with_state(&backoff, i + 1, &state);
+            state.record(dur);
+            assert_eq!(dur, sleep, "{i}");
+
+            // the deadline of the next attempt
+            assert_eq!(
+                attempt_started_at + elapsed + dur + backoff.current_backoff(i + 2),
+                current_deadline,
+                "{i}"
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let json = r#"{"initial_backoff":"1s","multiplier":1.6,"jitter":0.2,"max_backoff":"2m","min_connect_timeout":"20s"}"#;
+        let backoff = serde_json::from_str::<Backoff<crate::random::StdRandom>>(json).unwrap();
+        assert_eq!(serde_json::to_string(&backoff).unwrap(), json);
+    }
+}
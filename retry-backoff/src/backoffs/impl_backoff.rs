@@ -0,0 +1,91 @@
+//! [backoff](https://docs.rs/backoff) `ExponentialBackoff`, computed from its public fields.
+//!
+//! `max_elapsed_time` is measured from `start_time` by the crate's clock, which a shared policy can't reset
+//! per retry invocation, so it is ignored. Bound retries with the policy's max_retries or a timeout instead.
+
+use core::time::Duration;
+
+pub use backoff::{ExponentialBackoff as Backoff, ExponentialBackoffBuilder};
+
+use crate::{
+    backoffs::JitterMode, random::StdRandom, retry_backoff::RetryBackoff,
+    utils::secs_f64_to_duration,
+};
+
+/// `initial_interval * multiplier^(attempts - 1)`, capped at `max_interval` after the first attempt
+fn interval<C>(backoff: &backoff::exponential::ExponentialBackoff<C>, attempts: usize) -> Duration {
+    if attempts <= 1 {
+        return backoff.initial_interval;
+    }
+
+    let exponent = i32::try_from(attempts - 1).unwrap_or(i32::MAX);
+    secs_f64_to_duration(backoff.initial_interval.as_secs_f64() * backoff.multiplier.powi(exponent))
+        .min(backoff.max_interval)
+}
+
+/// `randomization_factor` above 1 is treated as 1
+fn jitter_mode<C>(backoff: &backoff::exponential::ExponentialBackoff<C>) -> JitterMode {
+    JitterMode::Proportional(backoff.randomization_factor)
+}
+
+//
+impl<C> RetryBackoff for backoff::exponential::ExponentialBackoff<C> {
+    fn delay(&self, attempts: usize) -> Duration {
+        jitter_mode(self).apply(interval(self, attempts), &StdRandom)
+    }
+
+    fn try_delay_bounds(&self, attempts: usize) -> Option<(Duration, Duration)> {
+        Some(jitter_mode(self).bounds(interval(self, attempts)))
+    }
+
+    fn name(&self) -> &str {
+        "CrateBackoff"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_impl_retry_backoff() {
+        let backoff = ExponentialBackoffBuilder::new()
+            .with_initial_interval(Duration::from_millis(100))
+            .with_randomization_factor(0.0)
+            .with_multiplier(2.0)
+            .with_max_interval(Duration::from_secs(1))
+            .build();
+
+        for (attempts, millis) in &[
+            (0, 100),
+            (1, 100),
+            (2, 200),
+            (3, 400),
+            (4, 800),
+            (5, 1000),
+            (usize::MAX, 1000),
+        ] {
+            assert_eq!(
+                RetryBackoff::delay(&backoff, *attempts),
+                Duration::from_millis(*millis)
+            );
+        }
+        assert_eq!(RetryBackoff::name(&backoff), "CrateBackoff");
+    }
+
+    #[test]
+    fn test_randomization_factor() {
+        // defaults: 500ms, x1.5, randomization_factor 0.5
+        let backoff = Backoff::default();
+
+        assert_eq!(
+            RetryBackoff::try_delay_bounds(&backoff, 2),
+            Some((Duration::from_millis(375), Duration::from_millis(1125)))
+        );
+        for attempts in 1..=10 {
+            let (low, high) = RetryBackoff::try_delay_bounds(&backoff, attempts).unwrap();
+            let dur = RetryBackoff::delay(&backoff, attempts);
+            assert!(dur >= low && dur <= high, "{attempts} {dur:?}");
+        }
+    }
+}
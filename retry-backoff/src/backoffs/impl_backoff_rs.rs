@@ -1,13 +1,89 @@
+//! [backoff-rs](https://github.com/rust-playground/backoff-rs) `Exponential`.
+//!
+//! `interval * factor^(attempts - 1)` plus random in `[0, jitter]`, capped at max.
+//! The crate keeps its parameters private, so `Backoff` reports the widest bounds,
+//! [`BoundedBackoff`] keeps them to report exact ones.
+
 use core::time::Duration;
 
 pub use backoff_rs::{Exponential as Backoff, ExponentialBackoffBuilder};
 
 use crate::retry_backoff::RetryBackoff;
 
+// the crate casts to i32, larger values would wrap negative
+fn exponent(attempts: usize) -> usize {
+    attempts.saturating_sub(1).min(i32::MAX as usize)
+}
+
 //
 impl RetryBackoff for Backoff {
     fn delay(&self, attempts: usize) -> Duration {
-        self.duration(attempts.saturating_sub(1))
+        self.duration(exponent(attempts))
+    }
+
+    /// the jitter and max can't be read
+    fn try_delay_bounds(&self, _attempts: usize) -> Option<(Duration, Duration)> {
+        Some((Duration::ZERO, Duration::MAX))
+    }
+
+    fn name(&self) -> &str {
+        "CrateBackoffRs"
+    }
+}
+
+//
+/// A `Backoff` built from the given parameters, kept for the bounds.
+pub struct BoundedBackoff {
+    inner: Backoff,
+    factor: f64,
+    interval: Duration,
+    jitter: Duration,
+    max: Option<Duration>,
+}
+
+impl BoundedBackoff {
+    pub fn new(factor: f64, interval: Duration, jitter: Duration, max: Option<Duration>) -> Self {
+        let builder = ExponentialBackoffBuilder::default()
+            .factor(factor)
+            .interval(interval)
+            .jitter(jitter);
+        let builder = match max {
+            Some(max) => builder.max(max),
+            None => builder,
+        };
+
+        Self {
+            inner: builder.build(),
+            factor,
+            interval,
+            jitter,
+            max,
+        }
+    }
+
+    pub fn inner(&self) -> &Backoff {
+        &self.inner
+    }
+
+    /// as the crate computes it, in f64 nanoseconds truncated to u64
+    fn cap(&self, nanos: f64) -> Duration {
+        let delay = Duration::from_nanos(nanos as u64);
+        match self.max {
+            Some(max) => delay.min(max),
+            None => delay,
+        }
+    }
+}
+
+impl RetryBackoff for BoundedBackoff {
+    fn delay(&self, attempts: usize) -> Duration {
+        RetryBackoff::delay(&self.inner, attempts)
+    }
+
+    fn try_delay_bounds(&self, attempts: usize) -> Option<(Duration, Duration)> {
+        let base = self.factor.powi(exponent(attempts) as i32) * self.interval.as_nanos() as f64;
+        let jitter = self.jitter.as_nanos() as f64;
+        Some((self.cap(base), self.cap(base + jitter)))
     }
 
     fn name(&self) -> &str {
@@ -27,7 +103,42 @@ mod tests {
             .max(Duration::from_secs(5))
             .build();
 
+        assert_eq!(RetryBackoff::delay(&backoff, 0), Duration::from_millis(500));
         assert_eq!(RetryBackoff::delay(&backoff, 1), Duration::from_millis(500));
+        assert_eq!(RetryBackoff::delay(&backoff, 2), Duration::from_millis(875));
+        assert_eq!(
+            RetryBackoff::delay(&backoff, usize::MAX),
+            Duration::from_secs(5)
+        );
         assert_eq!(RetryBackoff::name(&backoff), "CrateBackoffRs");
+        assert_eq!(
+            RetryBackoff::try_delay_bounds(&backoff, 1),
+            Some((Duration::ZERO, Duration::MAX))
+        );
+    }
+
+    #[test]
+    fn test_bounded_delay_bounds() {
+        let backoff = BoundedBackoff::new(
+            1.75,
+            Duration::from_millis(500),
+            Duration::from_millis(150),
+            Some(Duration::from_secs(5)),
+        );
+
+        for (attempts, min, max) in [
+            (1, 500, 650),
+            (2, 875, 1025),
+            (6, 5000, 5000),
+            (usize::MAX, 5000, 5000),
+        ] {
+            let (lower, upper) = RetryBackoff::try_delay_bounds(&backoff, attempts).unwrap();
+            assert_eq!(lower, Duration::from_millis(min), "{attempts}");
+            assert_eq!(upper, Duration::from_millis(max), "{attempts}");
+            for _ in 0..100 {
+                let delay = RetryBackoff::delay(&backoff, attempts);
+                assert!(lower <= delay && delay <= upper, "{attempts} {delay:?}");
+            }
+        }
     }
 }
@@ -0,0 +1,368 @@
+//! [backon](https://docs.rs/backon) builders, e.g. `ExponentialBuilder::default().with_max_times(5)`.
+//!
+//! Builders only expose the iterator they build, so each call replays a fresh one.
+//! `with_max_times` and `with_total_delay` exhaust the backoff.
+//!
+//! Jitter adds up to the unjittered delay, Fibonacci up to `min_delay`. Builders keep their
+//! parameters private but print them with Debug, the bounds replay them without jitter.
+//! With `with_total_delay`, jitter may exhaust the backoff before the bounds do.
+
+use alloc::{format, string::String};
+use core::{fmt, time::Duration};
+
+pub use backon::{BackoffBuilder, ConstantBuilder, ExponentialBuilder, FibonacciBuilder};
+
+use crate::{
+    retry_backoff::{BackoffState, RetryBackoff},
+    utils::nanos_to_duration,
+};
+
+// Past this every backon schedule has settled at its max delay, Duration::MAX or zero,
+// later attempts repeat the delay of the last simulated one.
+const MAX_SIMULATED_STEPS: usize = 1024;
+
+fn simulated_steps(attempts: usize) -> usize {
+    attempts.clamp(1, MAX_SIMULATED_STEPS)
+}
+
+//
+/// The Debug output of a builder, `ExponentialBuilder { jitter: true, factor: 2.0, .. }`.
+struct DebugFields(String);
+
+impl DebugFields {
+    fn new(builder: &impl fmt::Debug) -> Self {
+        Self(format!("{builder:?}"))
+    }
+
+    fn get(&self, name: &str) -> Option<&str> {
+        let key = format!(" {name}: ");
+        let rest = &self.0[self.0.find(&key)? + key.len()..];
+        Some(&rest[..rest.find([',', ' ']).unwrap_or(rest.len())])
+    }
+
+    fn jitter(&self) -> Option<bool> {
+        self.get("jitter")?.parse().ok()
+    }
+
+    fn factor(&self) -> Option<f32> {
+        self.get("factor")?.parse().ok()
+    }
+
+    fn duration(&self, name: &str) -> Option<Duration> {
+        parse_duration(self.get(name)?)
+    }
+
+    fn optional<T>(&self, name: &str, parse: impl FnOnce(&str) -> Option<T>) -> Option<Option<T>> {
+        match self.get(name)? {
+            "None" => Some(None),
+            value => parse(value.strip_prefix("Some(")?.strip_suffix(')')?).map(Some),
+        }
+    }
+
+    fn max_delay(&self) -> Option<Option<Duration>> {
+        self.optional("max_delay", parse_duration)
+    }
+
+    fn max_times(&self) -> Option<Option<usize>> {
+        self.optional("max_times", |value| value.parse().ok())
+    }
+}
+
+/// the Debug output of a `Duration`, `1.5s`, `100ms`, `10µs` or `0ns`
+fn parse_duration(value: &str) -> Option<Duration> {
+    let (number, unit) = [
+        ("ns", 1),
+        ("µs", 1_000),
+        ("ms", 1_000_000),
+        ("s", 1_000_000_000),
+    ]
+    .into_iter()
+    .find_map(|(suffix, unit)| Some((value.strip_suffix(suffix)?, unit)))?;
+    let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+
+    let mut nanos = whole.parse::<u128>().ok()?.checked_mul(unit)?;
+    let mut scale = unit;
+    for digit in fraction.chars() {
+        scale /= 10;
+        nanos += u128::from(digit.to_digit(10)?) * scale;
+    }
+    Some(nanos_to_duration(nanos))
+}
+
+//
+trait Unjittered: Sized {
+    /// the builder with the same parameters, without jitter
+    fn unjittered(fields: &DebugFields) -> Option<Self>;
+
+    /// the most jitter adds to a delay of the unjittered builder
+    fn max_jitter(&self, delay: Duration) -> Duration {
+        delay
+    }
+}
+
+impl Unjittered for ConstantBuilder {
+    fn unjittered(fields: &DebugFields) -> Option<Self> {
+        let builder = Self::new().with_delay(fields.duration("delay")?);
+        Some(match fields.max_times()? {
+            Some(max_times) => builder.with_max_times(max_times),
+            None => builder.without_max_times(),
+        })
+    }
+}
+
+impl Unjittered for ExponentialBuilder {
+    fn unjittered(fields: &DebugFields) -> Option<Self> {
+        let builder = Self::new()
+            .with_factor(fields.factor()?)
+            .with_min_delay(fields.duration("min_delay")?)
+            .with_total_delay(fields.optional("total_delay", parse_duration)?);
+        let builder = match fields.max_delay()? {
+            Some(max_delay) => builder.with_max_delay(max_delay),
+            None => builder.without_max_delay(),
+        };
+        Some(match fields.max_times()? {
+            Some(max_times) => builder.with_max_times(max_times),
+            None => builder.without_max_times(),
+        })
+    }
+}
+
+impl Unjittered for FibonacciBuilder {
+    fn unjittered(fields: &DebugFields) -> Option<Self> {
+        let builder = Self::new().with_min_delay(fields.duration("min_delay")?);
+        let builder = match fields.max_delay()? {
+            Some(max_delay) => builder.with_max_delay(max_delay),
+            None => builder.without_max_delay(),
+        };
+        Some(match fields.max_times()? {
+            Some(max_times) => builder.with_max_times(max_times),
+            None => builder.without_max_times(),
+        })
+    }
+
+    /// past the first delay, jitter is drawn from `min_delay`
+    fn max_jitter(&self, delay: Duration) -> Duration {
+        delay.min(self.build().next().unwrap_or_default())
+    }
+}
+
+macro_rules! impl_retry_backoff {
+    ($builder:ty, $name:literal) => {
+        impl RetryBackoff for $builder {
+            /// returns the last delay once exhausted
+            fn delay(&self, attempts: usize) -> Duration {
+                self.build()
+                    .take(simulated_steps(attempts))
+                    .last()
+                    .unwrap_or_default()
+            }
+
+            fn try_delay_with_state(
+                &self,
+                attempts: usize,
+                _state: &BackoffState,
+            ) -> Option<Duration> {
+                self.build().nth(simulated_steps(attempts) - 1)
+            }
+
+            /// the widest range when the Debug output can't be read
+            fn try_delay_bounds(&self, attempts: usize) -> Option<(Duration, Duration)> {
+                let steps = simulated_steps(attempts);
+                let fields = DebugFields::new(self);
+                match (
+                    fields.jitter(),
+                    <$builder as Unjittered>::unjittered(&fields),
+                ) {
+                    (Some(false), _) => self.build().nth(steps - 1).map(|delay| (delay, delay)),
+                    (Some(true), Some(builder)) => {
+                        let delay = builder.build().nth(steps - 1)?;
+                        Some((delay, delay.saturating_add(builder.max_jitter(delay))))
+                    }
+                    _ => self
+                        .build()
+                        .nth(steps - 1)
+                        .map(|_| (Duration::ZERO, Duration::MAX)),
+                }
+            }
+
+            fn name(&self) -> &str {
+                $name
+            }
+        }
+    };
+}
+
+impl_retry_backoff!(ConstantBuilder, "CrateBackonConstant");
+impl_retry_backoff!(ExponentialBuilder, "CrateBackonExponential");
+impl_retry_backoff!(FibonacciBuilder, "CrateBackonFibonacci");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constant() {
+        let backoff = ConstantBuilder::default()
+            .with_delay(Duration::from_millis(100))
+            .with_max_times(2);
+
+        assert_eq!(RetryBackoff::delay(&backoff, 1), Duration::from_millis(100));
+        assert_eq!(
+            RetryBackoff::try_delay(&backoff, 2),
+            Some(Duration::from_millis(100))
+        );
+        assert_eq!(RetryBackoff::try_delay(&backoff, 3), None);
+        assert_eq!(RetryBackoff::delay(&backoff, 3), Duration::from_millis(100));
+        assert_eq!(RetryBackoff::name(&backoff), "CrateBackonConstant");
+    }
+
+    #[test]
+    fn test_exponential() {
+        let backoff = ExponentialBuilder::default()
+            .with_min_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_secs(1))
+            .without_max_times();
+
+        // backon multiplies in f32
+        for (attempts, millis) in &[
+            (0, 100),
+            (1, 100),
+            (2, 200),
+            (3, 400),
+            (4, 800),
+            (5, 1000),
+            (usize::MAX, 1000),
+        ] {
+            assert!(
+                RetryBackoff::delay(&backoff, *attempts).abs_diff(Duration::from_millis(*millis))
+                    < Duration::from_micros(1)
+            );
+        }
+        assert_eq!(RetryBackoff::name(&backoff), "CrateBackonExponential");
+
+        // default max_times is 3
+        let backoff = ExponentialBuilder::default();
+        assert_eq!(
+            RetryBackoff::try_delay(&backoff, 3),
+            Some(Duration::from_secs(4))
+        );
+        assert_eq!(RetryBackoff::try_delay(&backoff, 4), None);
+
+        let backoff = ExponentialBuilder::default()
+            .with_min_delay(Duration::from_millis(100))
+            .with_jitter();
+        for attempts in 1..=3 {
+            let dur = RetryBackoff::delay(&backoff, attempts);
+            let delay = Duration::from_millis(100) * 2_u32.pow(attempts as u32 - 1);
+            assert!(dur >= delay && dur <= delay * 2, "{attempts} {dur:?}");
+        }
+    }
+
+    #[test]
+    fn test_delay_bounds() {
+        let backoff = ExponentialBuilder::default()
+            .with_min_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_secs(1))
+            .without_max_times();
+        // backon multiplies in f32
+        let (low, high) = RetryBackoff::try_delay_bounds(&backoff, 2).unwrap();
+        assert_eq!(low, high);
+        assert!(low.abs_diff(Duration::from_millis(200)) < Duration::from_micros(1));
+
+        let jittered = backoff.with_jitter();
+        for attempts in [1, 2, 5, usize::MAX] {
+            let delay = RetryBackoff::delay(&backoff, attempts);
+            assert_eq!(
+                RetryBackoff::try_delay_bounds(&jittered, attempts),
+                Some((delay, delay * 2))
+            );
+            for _ in 0..100 {
+                let dur = RetryBackoff::delay(&jittered, attempts);
+                assert!(delay <= dur && dur <= delay * 2, "{attempts} {dur:?}");
+            }
+        }
+
+        let backoff = ConstantBuilder::default()
+            .with_delay(Duration::from_millis(100))
+            .with_jitter()
+            .with_max_times(1);
+        assert_eq!(
+            RetryBackoff::try_delay_bounds(&backoff, 1),
+            Some((Duration::from_millis(100), Duration::from_millis(200)))
+        );
+        assert_eq!(RetryBackoff::try_delay_bounds(&backoff, 2), None);
+
+        // jitter is drawn from min_delay
+        let backoff = FibonacciBuilder::default()
+            .with_min_delay(Duration::from_millis(100))
+            .with_jitter()
+            .without_max_times();
+        for (attempts, millis) in [(1, 100), (2, 100), (3, 200), (5, 500)] {
+            let delay = Duration::from_millis(millis);
+            assert_eq!(
+                RetryBackoff::try_delay_bounds(&backoff, attempts),
+                Some((delay, delay + Duration::from_millis(100)))
+            );
+        }
+    }
+
+    #[test]
+    fn test_debug_fields() {
+        for (value, expected) in [
+            ("0ns", Duration::ZERO),
+            ("10µs", Duration::from_micros(10)),
+            ("1.5ms", Duration::from_micros(1500)),
+            ("1.000000001s", Duration::new(1, 1)),
+            ("18446744073709551615.999999999s", Duration::MAX),
+        ] {
+            assert_eq!(parse_duration(value), Some(expected), "{value}");
+        }
+        assert_eq!(parse_duration("1h"), None);
+
+        // replays every parameter
+        let backoff = ExponentialBuilder::default()
+            .with_factor(1.5)
+            .with_min_delay(Duration::from_millis(250))
+            .with_max_delay(Duration::from_secs(3))
+            .with_max_times(7)
+            .with_total_delay(Some(Duration::from_secs(10)));
+        let fields = DebugFields::new(&backoff.with_jitter().with_jitter_seed(1));
+        assert_eq!(fields.jitter(), Some(true));
+        let replayed = ExponentialBuilder::unjittered(&fields).unwrap();
+        assert!(backoff.build().eq(replayed.build()));
+
+        let backoff = FibonacciBuilder::default().without_max_delay();
+        let replayed = FibonacciBuilder::unjittered(&DebugFields::new(&backoff)).unwrap();
+        assert!(backoff.build().take(50).eq(replayed.build().take(50)));
+
+        let backoff = ConstantBuilder::default().without_max_times();
+        let replayed = ConstantBuilder::unjittered(&DebugFields::new(&backoff)).unwrap();
+        assert!(backoff.build().take(10).eq(replayed.build().take(10)));
+    }
+
+    #[test]
+    fn test_fibonacci() {
+        let backoff = FibonacciBuilder::default()
+            .with_min_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_secs(1))
+            .without_max_times();
+
+        // backon stops growing once past max_delay, without capping
+        for (attempts, millis) in &[
+            (1, 100),
+            (2, 100),
+            (3, 200),
+            (4, 300),
+            (5, 500),
+            (6, 800),
+            (7, 1300),
+            (usize::MAX, 1300),
+        ] {
+            assert_eq!(
+                RetryBackoff::delay(&backoff, *attempts),
+                Duration::from_millis(*millis)
+            );
+        }
+        assert_eq!(RetryBackoff::name(&backoff), "CrateBackonFibonacci");
+    }
+}
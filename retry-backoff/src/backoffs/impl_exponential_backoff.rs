@@ -5,7 +5,7 @@ use core::{
 
 use exponential_backoff::Backoff as Inner;
 
-use crate::retry_backoff::RetryBackoff;
+use crate::retry_backoff::{BackoffState, RetryBackoff};
 
 //
 #[derive(Debug, Clone)]
@@ -33,13 +33,50 @@ impl Backoff {
     }
 }
 
+// The crate's exponent saturates well before this, later attempts repeat the same delay.
+const MAX_STEPS: usize = 64;
+
+impl Backoff {
+    /// the iterator index for attempts, None once past `max_attempts`
+    /// the crate yields no delay for its last attempt
+    fn index(&self, attempts: usize) -> Option<usize> {
+        let index = attempts.saturating_sub(1);
+        (index < (self.max_attempts() as usize).saturating_sub(1)).then_some(index.min(MAX_STEPS))
+    }
+}
+
 //
 impl RetryBackoff for Backoff {
+    /// returns max once past `max_attempts`
     fn delay(&self, attempts: usize) -> Duration {
-        self.iter()
-            .nth(attempts)
-            .unwrap_or_else(|| Some(*self.max()))
-            .unwrap_or_else(|| *self.max())
+        self.try_delay(attempts).unwrap_or_else(|| *self.max())
+    }
+
+    fn try_delay_with_state(&self, attempts: usize, _state: &BackoffState) -> Option<Duration> {
+        self.iter().nth(self.index(attempts)?).flatten()
+    }
+
+    /// mirrors the crate's integer jitter, up to `jitter - 1%` below and above
+    fn try_delay_bounds(&self, attempts: usize) -> Option<(Duration, Duration)> {
+        let index = self.index(attempts)?;
+        let delay = self
+            .min()
+            .saturating_mul(self.factor().saturating_pow(index as u32));
+
+        let jitter_factor = (self.jitter() * 100.0) as u32;
+        let (low, high) = if jitter_factor == 0 {
+            (delay, delay)
+        } else {
+            let scaled = delay.saturating_mul(100);
+            let spread = scaled.saturating_mul(jitter_factor - 1) / 100;
+            (
+                scaled.saturating_sub(spread) / 100,
+                scaled.saturating_add(spread) / 100,
+            )
+        };
+
+        let clamp = |delay: Duration| delay.max(*self.min()).min(*self.max());
+        Some((clamp(low), clamp(high)))
     }
 
     fn name(&self) -> &str {
@@ -55,23 +92,60 @@ mod tests {
     fn test_impl_retry_backoff() {
         let mut backoff = Backoff::new(Duration::from_millis(100), None);
         backoff.set_max(Duration::from_secs(1));
+        backoff.set_jitter(0.0);
+
+        for (attempts, millis) in &[
+            (0, 100),
+            (1, 100),
+            (2, 200),
+            (3, 400),
+            (4, 800),
+            (5, 1000),
+            (100, 1000),
+            (usize::MAX, 1000),
+        ] {
+            assert_eq!(
+                RetryBackoff::delay(&backoff, *attempts),
+                Duration::from_millis(*millis)
+            );
+        }
+        assert_eq!(RetryBackoff::name(&backoff), "CrateExponentialBackoff");
+    }
+
+    #[test]
+    fn test_jitter() {
+        let mut backoff = Backoff::new(Duration::from_millis(100), None);
+        backoff.set_max(Duration::from_secs(1));
 
-        assert!(RetryBackoff::delay(&backoff, 1) > Duration::from_millis(100));
-        assert!(RetryBackoff::delay(&backoff, 2) > Duration::from_millis(200));
-        assert!(RetryBackoff::delay(&backoff, 8) > Duration::from_millis(800));
-        assert!(RetryBackoff::delay(&backoff, 9) > Duration::from_millis(900));
         assert_eq!(
-            RetryBackoff::delay(&backoff, 10),
-            Duration::from_millis(1000)
+            RetryBackoff::try_delay_bounds(&backoff, 2),
+            Some((Duration::from_millis(142), Duration::from_millis(258)))
         );
+        for attempts in 1..=10 {
+            let (low, high) = RetryBackoff::try_delay_bounds(&backoff, attempts).unwrap();
+            for _ in 0..20 {
+                let dur = RetryBackoff::delay(&backoff, attempts);
+                assert!(dur >= low && dur <= high, "{attempts} {dur:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_max_attempts() {
+        let mut backoff = Backoff::new(Duration::from_millis(100), Duration::from_secs(1));
+        backoff.set_jitter(0.0);
+        backoff.set_max_attempts(3);
+
         assert_eq!(
-            RetryBackoff::delay(&backoff, 11),
-            Duration::from_millis(1000)
+            RetryBackoff::try_delay(&backoff, 1),
+            Some(Duration::from_millis(100))
         );
         assert_eq!(
-            RetryBackoff::delay(&backoff, 100),
-            Duration::from_millis(1000)
+            RetryBackoff::try_delay(&backoff, 2),
+            Some(Duration::from_millis(200))
         );
-        assert_eq!(RetryBackoff::name(&backoff), "CrateExponentialBackoff");
+        assert_eq!(RetryBackoff::try_delay(&backoff, 3), None);
+        assert_eq!(RetryBackoff::try_delay_bounds(&backoff, 3), None);
+        assert_eq!(RetryBackoff::delay(&backoff, 3), Duration::from_secs(1));
     }
 }
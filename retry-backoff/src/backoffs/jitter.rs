@@ -0,0 +1,260 @@
+//! [Ref Exponential Backoff And Jitter](https://aws.amazon.com/blogs/architecture/exponential-backoff-and-jitter/)
+
+use core::{fmt, time::Duration};
+
+use crate::{
+    description::Description,
+    random::RandomSource,
+    retry_backoff::{BackoffState, RetryBackoff},
+    utils::secs_f64_to_duration,
+};
+
+//
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum JitterMode {
+    /// random in `[0, delay]`
+    Full,
+    /// `delay / 2` plus random in `[0, delay / 2]`
+    Equal,
+    /// random in `[delay * (1 - ratio), delay * (1 + ratio)]`, ratio is clamped to `[0, 1]`
+    Proportional(f64),
+}
+
+//
+#[derive(Clone)]
+pub struct Jitter<B, R> {
+    inner: B,
+    mode: JitterMode,
+    rng: R,
+}
+
+impl<B, R> fmt::Debug for Jitter<B, R>
+where
+    B: RetryBackoff,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Jitter")
+            .field("inner", &self.inner.name())
+            .field("mode", &self.mode)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<B, R> Jitter<B, R> {
+    pub fn new(inner: B, mode: JitterMode, rng: R) -> Self {
+        Self { inner, mode, rng }
+    }
+
+    pub fn full(inner: B, rng: R) -> Self {
+        Self::new(inner, JitterMode::Full, rng)
+    }
+
+    pub fn equal(inner: B, rng: R) -> Self {
+        Self::new(inner, JitterMode::Equal, rng)
+    }
+
+    pub fn proportional(inner: B, ratio: f64, rng: R) -> Self {
+        Self::new(inner, JitterMode::Proportional(ratio), rng)
+    }
+
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    pub fn mode(&self) -> JitterMode {
+        self.mode
+    }
+}
+
+impl JitterMode {
+    /// returns the `[low, high]` range a jittered delay is drawn from
+    pub fn bounds(&self, delay: Duration) -> (Duration, Duration) {
+        match *self {
+            JitterMode::Full => (Duration::ZERO, delay),
+            JitterMode::Equal => (delay / 2, delay),
+            JitterMode::Proportional(ratio) => {
+                let ratio = if ratio.is_nan() {
+                    0.0
+                } else {
+                    ratio.clamp(0.0, 1.0)
+                };
+                let spread = secs_f64_to_duration(delay.as_secs_f64() * ratio).min(delay);
+                (delay - spread, delay.saturating_add(spread))
+            }
+        }
+    }
+
+    pub fn apply<R>(&self, delay: Duration, rng: &R) -> Duration
+    where
+        R: RandomSource + ?Sized,
+    {
+        let (low, high) = self.bounds(delay);
+        rng.duration_between(low, high)
+    }
+}
+
+//
+impl<B, R> RetryBackoff for Jitter<B, R>
+where
+    B: RetryBackoff,
+    R: RandomSource,
+{
+    fn delay(&self, attempts: usize) -> Duration {
+        self.mode.apply(self.inner.delay(attempts), &self.rng)
+    }
+
+    fn delay_with_state(&self, attempts: usize, state: &BackoffState) -> Duration {
+        self.mode
+            .apply(self.inner.delay_with_state(attempts, state), &self.rng)
+    }
+
+    fn try_delay_with_state(&self, attempts: usize, state: &BackoffState) -> Option<Duration> {
+        self.inner
+            .try_delay_with_state(attempts, state)
+            .map(|delay| self.mode.apply(delay, &self.rng))
+    }
+
+    fn try_delay_bounds(&self, attempts: usize) -> Option<(Duration, Duration)> {
+        self.inner
+            .try_delay_bounds(attempts)
+            .map(|(min, max)| (self.mode.bounds(min).0, self.mode.bounds(max).1))
+    }
+
+    fn attempt_timeout(&self, attempts: usize) -> Option<Duration> {
+        self.inner.attempt_timeout(attempts)
+    }
+
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        params("inner", &Description::new(&self.inner));
+        params("mode", &self.mode);
+    }
+
+    fn name(&self) -> &str {
+        "Jitter"
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::random::SeededRandom;
+
+    struct Fixed(Duration);
+    impl RetryBackoff for Fixed {
+        fn delay(&self, _attempts: usize) -> Duration {
+            self.0
+        }
+
+        fn name(&self) -> &str {
+            "Fixed"
+        }
+    }
+
+    #[test]
+    fn test_bounds() {
+        let delay = Duration::from_secs(10);
+
+        assert_eq!(JitterMode::Full.bounds(delay), (Duration::ZERO, delay));
+        assert_eq!(
+            JitterMode::Equal.bounds(delay),
+            (Duration::from_secs(5), delay)
+        );
+        assert_eq!(
+            JitterMode::Proportional(0.2).bounds(delay),
+            (Duration::from_secs(8), Duration::from_secs(12))
+        );
+        assert_eq!(
+            JitterMode::Proportional(2.0).bounds(delay),
+            (Duration::ZERO, Duration::from_secs(20))
+        );
+        assert_eq!(
+            JitterMode::Proportional(f64::NAN).bounds(delay),
+            (delay, delay)
+        );
+        assert_eq!(
+            JitterMode::Proportional(1.0).bounds(Duration::MAX),
+            (Duration::ZERO, Duration::MAX)
+        );
+    }
+
+    #[test]
+    fn test_impl_retry_backoff() {
+        let delay = Duration::from_secs(10);
+
+        for mode in [
+            JitterMode::Full,
+            JitterMode::Equal,
+            JitterMode::Proportional(0.2),
+        ] {
+            let backoff = Jitter::new(Fixed(delay), mode, SeededRandom::new(1));
+            let (low, high) = mode.bounds(delay);
+
+            let mut distinct = false;
+            let first = RetryBackoff::delay(&backoff, 1);
+            for attempts in 1..=100 {
+                let dur = RetryBackoff::delay(&backoff, attempts);
+                assert!(dur >= low && dur <= high, "{mode:?} {dur:?}");
+                distinct |= dur != first;
+            }
+            assert!(distinct);
+        }
+
+        let backoff = Jitter::full(Fixed(delay), SeededRandom::new(1));
+        assert_eq!(RetryBackoff::name(&backoff), "Jitter");
+    }
+
+    #[test]
+    fn test_try_delay_bounds() {
+        let delay = Duration::from_secs(10);
+
+        for mode in [
+            JitterMode::Full,
+            JitterMode::Equal,
+            JitterMode::Proportional(0.2),
+        ] {
+            let backoff = Jitter::new(Fixed(delay), mode, SeededRandom::new(1));
+            assert_eq!(
+                RetryBackoff::try_delay_bounds(&backoff, 1),
+                Some(mode.bounds(delay))
+            );
+        }
+
+        // nested, the outer range widens the inner one
+        let backoff = Jitter::equal(
+            Jitter::proportional(Fixed(delay), 0.2, SeededRandom::new(1)),
+            SeededRandom::new(2),
+        );
+        assert_eq!(
+            RetryBackoff::try_delay_bounds(&backoff, 1),
+            Some((Duration::from_secs(4), Duration::from_secs(12)))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_debug() {
+        let backoff = Jitter::full(Fixed(Duration::from_secs(1)), SeededRandom::new(1));
+        assert_eq!(
+            alloc::format!("{backoff:?}"),
+            r#"Jitter { inner: "Fixed", mode: Full, .. }"#
+        );
+    }
+
+    #[test]
+    fn test_deterministic_with_seed() {
+        let delay = Duration::from_secs(1);
+        let a = Jitter::equal(Fixed(delay), SeededRandom::new(7));
+        let b = Jitter::equal(Fixed(delay), SeededRandom::new(7));
+
+        for attempts in 1..=10 {
+            assert_eq!(
+                RetryBackoff::delay(&a, attempts),
+                RetryBackoff::delay(&b, attempts)
+            );
+        }
+    }
+}
@@ -0,0 +1,427 @@
+//! [client-go wait.Backoff](https://pkg.go.dev/k8s.io/apimachinery/pkg/util/wait#Backoff)
+//!
+//! `Step()` returns `Duration`, then multiplies it by `Factor`, up to `Steps` times.
+//! Going above `Cap` sets it to `Cap` and ends the steps, after which the duration freezes.
+//! `Jitter` adds `random_between(0, Jitter * duration)`.
+//!
+//! Serde uses client-go's field names, with durations as nanoseconds like Go's `time.Duration`.
+
+use core::{fmt, time::Duration};
+
+use crate::{
+    random::RandomSource,
+    retry_backoff::{BackoffState, RetryBackoff},
+    utils::secs_f64_to_duration,
+};
+
+//
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "PascalCase"))]
+pub struct Backoff<R> {
+    #[cfg_attr(feature = "serde", serde(with = "go_duration"))]
+    pub duration: Duration,
+    pub factor: f64,
+    pub jitter: f64,
+    /// less than 1 freezes at `duration`
+    pub steps: i64,
+    /// zero is no cap
+    #[cfg_attr(feature = "serde", serde(with = "go_duration"))]
+    pub cap: Duration,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    steps_exhausted: StepsExhausted,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    rng: R,
+}
+
+/// What happens once `Steps` run out.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StepsExhausted {
+    /// like `Step()`, stays at the last duration, which is `Cap` when reached
+    #[default]
+    Freeze,
+    /// like `wait.ExponentialBackoff`, at most `Steps` attempts,
+    /// up to the one whose `Step()` goes above `Cap`
+    Stop,
+}
+
+impl<R> Backoff<R> {
+    pub fn new(
+        duration: Duration,
+        factor: f64,
+        jitter: f64,
+        steps: i64,
+        cap: Duration,
+        rng: R,
+    ) -> Self {
+        Self {
+            duration,
+            factor,
+            jitter,
+            steps,
+            cap,
+            steps_exhausted: StepsExhausted::default(),
+            rng,
+        }
+    }
+
+    pub fn stop_after_steps(mut self) -> Self {
+        self.steps_exhausted = StepsExhausted::Stop;
+        self
+    }
+
+    pub fn steps_exhausted(&self) -> StepsExhausted {
+        self.steps_exhausted
+    }
+
+    fn steps(&self) -> usize {
+        usize::try_from(self.steps).unwrap_or(0)
+    }
+
+    /// the duration returned by the attempts-th `Step()`, before jitter
+    /// attempts 0 is treated as 1
+    pub fn step_duration(&self, attempts: usize) -> Duration {
+        let completed_steps = attempts.saturating_sub(1).min(self.steps());
+        if completed_steps == 0 || self.factor == 0.0 {
+            return self.duration;
+        }
+
+        // a shrinking factor can only go above cap on the first step
+        let duration = self.grow(completed_steps);
+        if !self.cap.is_zero() && (duration > self.cap || self.grow(1) > self.cap) {
+            self.cap
+        } else {
+            duration
+        }
+    }
+
+    /// the duration after steps `Step()`, before cap
+    fn grow(&self, steps: usize) -> Duration {
+        let exponent = i32::try_from(steps).unwrap_or(i32::MAX);
+        secs_f64_to_duration(self.duration.as_secs_f64() * self.factor.powi(exponent))
+    }
+
+    /// the first `Step()` going above cap, which sets `Steps` to 0
+    fn capped_step(&self) -> Option<usize> {
+        if self.cap.is_zero() || self.factor == 0.0 {
+            return None;
+        }
+
+        let above_cap = |step: usize| self.grow(step) > self.cap;
+        if above_cap(1) {
+            return Some(1);
+        }
+        if self.factor <= 1.0 || !above_cap(self.steps()) {
+            return None;
+        }
+
+        // growing, above cap at high only
+        let (mut low, mut high) = (1, self.steps());
+        while high - low > 1 {
+            let mid = low + (high - low) / 2;
+            if above_cap(mid) {
+                high = mid;
+            } else {
+                low = mid;
+            }
+        }
+        Some(high)
+    }
+
+    fn jitter_bounds(&self, duration: Duration) -> (Duration, Duration) {
+        if self.jitter > 0.0 {
+            let spread = secs_f64_to_duration(duration.as_secs_f64() * self.jitter);
+            (duration, duration.saturating_add(spread))
+        } else {
+            (duration, duration)
+        }
+    }
+
+    fn is_exhausted(&self, attempts: usize) -> bool {
+        if self.steps_exhausted != StepsExhausted::Stop {
+            return false;
+        }
+        let last_attempts = match self.capped_step() {
+            Some(step) => step.min(self.steps()),
+            None => self.steps(),
+        };
+        attempts.max(1) >= last_attempts
+    }
+}
+
+//
+impl<R> RetryBackoff for Backoff<R>
+where
+    R: RandomSource,
+{
+    /// Once exhausted, the last duration.
+    fn delay(&self, attempts: usize) -> Duration {
+        let (low, high) = self.jitter_bounds(self.step_duration(attempts));
+        self.rng.duration_between(low, high)
+    }
+
+    fn try_delay_with_state(&self, attempts: usize, state: &BackoffState) -> Option<Duration> {
+        if self.is_exhausted(attempts) {
+            return None;
+        }
+        Some(self.delay_with_state(attempts, state))
+    }
+
+    fn try_delay_bounds(&self, attempts: usize) -> Option<(Duration, Duration)> {
+        if self.is_exhausted(attempts) {
+            return None;
+        }
+        Some(self.jitter_bounds(self.step_duration(attempts)))
+    }
+
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        params("duration", &self.duration);
+        params("factor", &self.factor);
+        params("jitter", &self.jitter);
+        params("steps", &self.steps);
+        params("cap", &self.cap);
+        params("steps_exhausted", &self.steps_exhausted);
+    }
+
+    fn name(&self) -> &str {
+        "Kubernetes"
+    }
+}
+
+//
+#[cfg(feature = "serde")]
+mod go_duration {
+    use core::time::Duration;
+
+    use serde::{Deserialize as _, Deserializer, Serializer};
+
+    use crate::utils::nanos_to_duration;
+
+    pub(super) fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i64(i64::try_from(duration.as_nanos()).unwrap_or(i64::MAX))
+    }
+
+    /// negative is zero
+    pub(super) fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let nanos = i64::deserialize(deserializer)?;
+        Ok(nanos_to_duration(nanos.max(0) as u128))
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::random::SeededRandom;
+
+    const MILLI: Duration = Duration::from_millis(1);
+
+    #[test]
+    fn test_step_duration() {
+        // Ref retry.DefaultBackoff
+        let backoff = Backoff::new(
+            10 * MILLI,
+            1.0,
+            0.1,
+            4,
+            Duration::ZERO,
+            SeededRandom::new(1),
+        );
+        for attempts in [0, 1, 4, 5, usize::MAX] {
+            assert_eq!(backoff.step_duration(attempts), 10 * MILLI);
+        }
+
+        let backoff = Backoff::new(
+            10 * MILLI,
+            2.0,
+            0.0,
+            5,
+            Duration::from_millis(50),
+            SeededRandom::new(1),
+        );
+        for (attempts, millis) in &[
+            (0, 10),
+            (1, 10),
+            (2, 20),
+            (3, 40),
+            (4, 50),
+            (5, 50),
+            (100, 50),
+        ] {
+            assert_eq!(
+                backoff.step_duration(*attempts),
+                Duration::from_millis(*millis),
+                "{attempts}"
+            );
+        }
+
+        // freezes once the steps run out
+        let backoff = Backoff::new(
+            10 * MILLI,
+            2.0,
+            0.0,
+            2,
+            Duration::ZERO,
+            SeededRandom::new(1),
+        );
+        for (attempts, millis) in &[(1, 10), (2, 20), (3, 40), (4, 40), (usize::MAX, 40)] {
+            assert_eq!(
+                backoff.step_duration(*attempts),
+                Duration::from_millis(*millis),
+                "{attempts}"
+            );
+        }
+
+        for steps in [0, -1] {
+            let backoff = Backoff::new(
+                10 * MILLI,
+                2.0,
+                0.0,
+                steps,
+                Duration::ZERO,
+                SeededRandom::new(1),
+            );
+            assert_eq!(backoff.step_duration(3), 10 * MILLI);
+        }
+
+        // a zero factor never changes the duration, not even above cap
+        let backoff = Backoff::new(10 * MILLI, 0.0, 0.0, 5, MILLI, SeededRandom::new(1));
+        assert_eq!(backoff.step_duration(3), 10 * MILLI);
+
+        // above cap from the start, capped after the first step
+        let backoff = Backoff::new(10 * MILLI, 0.5, 0.0, 5, MILLI, SeededRandom::new(1));
+        assert_eq!(backoff.step_duration(1), 10 * MILLI);
+        assert_eq!(backoff.step_duration(2), MILLI);
+        assert_eq!(backoff.step_duration(5), MILLI);
+
+        let backoff = Backoff::new(
+            Duration::MAX,
+            2.0,
+            0.0,
+            i64::MAX,
+            Duration::ZERO,
+            SeededRandom::new(1),
+        );
+        assert_eq!(backoff.step_duration(usize::MAX), Duration::MAX);
+    }
+
+    #[test]
+    fn test_impl_retry_backoff() {
+        let backoff = Backoff::new(
+            10 * MILLI,
+            2.0,
+            0.5,
+            5,
+            Duration::from_millis(50),
+            SeededRandom::new(1),
+        );
+
+        for attempts in 1..=10 {
+            let duration = backoff.step_duration(attempts);
+            let dur = RetryBackoff::delay(&backoff, attempts);
+            assert!(dur >= duration && dur <= duration * 3 / 2, "{dur:?}");
+        }
+        assert_eq!(
+            RetryBackoff::try_delay_bounds(&backoff, 3),
+            Some((Duration::from_millis(40), Duration::from_millis(60)))
+        );
+        assert!(RetryBackoff::try_delay(&backoff, usize::MAX).is_some());
+        assert_eq!(backoff.steps_exhausted(), StepsExhausted::Freeze);
+        assert_eq!(RetryBackoff::name(&backoff), "Kubernetes");
+
+        // Ref wait.ExponentialBackoff, 3 attempts
+        let backoff = Backoff::new(
+            10 * MILLI,
+            2.0,
+            0.0,
+            3,
+            Duration::ZERO,
+            SeededRandom::new(1),
+        )
+        .stop_after_steps();
+        assert_eq!(backoff.steps_exhausted(), StepsExhausted::Stop);
+        assert_eq!(RetryBackoff::try_delay(&backoff, 1), Some(10 * MILLI));
+        assert_eq!(RetryBackoff::try_delay(&backoff, 2), Some(20 * MILLI));
+        assert_eq!(RetryBackoff::try_delay(&backoff, 3), None);
+        assert_eq!(RetryBackoff::try_delay_bounds(&backoff, 3), None);
+        assert_eq!(RetryBackoff::delay(&backoff, 3), 40 * MILLI);
+
+        let backoff = Backoff::new(
+            10 * MILLI,
+            2.0,
+            0.0,
+            0,
+            Duration::ZERO,
+            SeededRandom::new(1),
+        )
+        .stop_after_steps();
+        assert_eq!(RetryBackoff::try_delay(&backoff, 1), None);
+
+        // the third Step() goes above cap and ends the steps, 3 attempts
+        let backoff = Backoff::new(
+            10 * MILLI,
+            2.0,
+            0.0,
+            5,
+            Duration::from_millis(50),
+            SeededRandom::new(1),
+        )
+        .stop_after_steps();
+        assert_eq!(RetryBackoff::try_delay(&backoff, 1), Some(10 * MILLI));
+        assert_eq!(RetryBackoff::try_delay(&backoff, 2), Some(20 * MILLI));
+        assert_eq!(RetryBackoff::try_delay(&backoff, 3), None);
+        assert_eq!(RetryBackoff::try_delay_bounds(&backoff, 4), None);
+
+        // above cap on the first Step(), 1 attempt
+        let backoff =
+            Backoff::new(10 * MILLI, 0.5, 0.0, 5, MILLI, SeededRandom::new(1)).stop_after_steps();
+        assert_eq!(RetryBackoff::try_delay(&backoff, 1), None);
+
+        // cap reached after the steps run out
+        let backoff = Backoff::new(
+            10 * MILLI,
+            2.0,
+            0.0,
+            3,
+            Duration::from_millis(50),
+            SeededRandom::new(1),
+        )
+        .stop_after_steps();
+        assert_eq!(RetryBackoff::try_delay(&backoff, 2), Some(20 * MILLI));
+        assert_eq!(RetryBackoff::try_delay(&backoff, 3), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        use crate::random::StdRandom;
+
+        let json = r#"{"Duration":10000000,"Factor":5.0,"Jitter":0.1,"Steps":4,"Cap":1000000000}"#;
+        let backoff = serde_json::from_str::<Backoff<StdRandom>>(json).unwrap();
+        assert_eq!(backoff.duration, 10 * MILLI);
+        assert_eq!(backoff.factor, 5.0);
+        assert_eq!(backoff.jitter, 0.1);
+        assert_eq!(backoff.steps, 4);
+        assert_eq!(backoff.cap, Duration::from_secs(1));
+        assert_eq!(backoff.steps_exhausted(), StepsExhausted::Freeze);
+        assert_eq!(serde_json::to_string(&backoff).unwrap(), json);
+
+        let json = r#"{"Duration":-1,"Factor":0.0,"Jitter":0.0,"Steps":-1,"Cap":0}"#;
+        let backoff = serde_json::from_str::<Backoff<StdRandom>>(json).unwrap();
+        assert_eq!(backoff.duration, Duration::ZERO);
+
+        let backoff = Backoff::new(Duration::MAX, 1.0, 0.0, 1, Duration::ZERO, StdRandom);
+        assert!(
+            serde_json::to_string(&backoff)
+                .unwrap()
+                .starts_with(&format!(r#"{{"Duration":{}"#, i64::MAX))
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! Delay proportional to how long the failed attempt took.
+//!
+//! `sleep = min(max, max(min, last_attempt_elapsed * factor))`, `min` until an attempt is measured.
+
+use core::{fmt, time::Duration};
+
+use crate::{
+    retry_backoff::{BackoffState, RetryBackoff},
+    utils::secs_f64_to_duration,
+};
+
+//
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Backoff {
+    pub factor: f64,
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub min: Duration,
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub max: Duration,
+}
+
+impl Backoff {
+    pub const fn new(factor: f64, min: Duration, max: Duration) -> Self {
+        Self { factor, min, max }
+    }
+
+    /// max wins over min
+    pub fn delay_for_elapsed(&self, elapsed: Duration) -> Duration {
+        secs_f64_to_duration(elapsed.as_secs_f64() * self.factor)
+            .max(self.min)
+            .min(self.max)
+    }
+}
+
+//
+impl RetryBackoff for Backoff {
+    /// Without a measured attempt, min.
+    fn delay(&self, _attempts: usize) -> Duration {
+        self.min.min(self.max)
+    }
+
+    fn delay_with_state(&self, attempts: usize, state: &BackoffState) -> Duration {
+        match state.last_attempt_elapsed {
+            Some(elapsed) => self.delay_for_elapsed(elapsed),
+            None => self.delay(attempts),
+        }
+    }
+
+    /// the bounds over any measured attempt
+    fn try_delay_bounds(&self, _attempts: usize) -> Option<(Duration, Duration)> {
+        Some((self.min.min(self.max), self.max))
+    }
+
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        params("factor", &self.factor);
+        params("min", &self.min);
+        params("max", &self.max);
+    }
+
+    fn name(&self) -> &str {
+        "LatencyAware"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MIN: Duration = Duration::from_millis(100);
+    const MAX: Duration = Duration::from_secs(30);
+
+    #[test]
+    fn test_delay_with_state() {
+        let backoff = Backoff::new(2.0, MIN, MAX);
+
+        let mut state = BackoffState::new();
+        assert_eq!(RetryBackoff::delay_with_state(&backoff, 1, &state), MIN);
+
+        for (elapsed, delay) in [
+            (Duration::from_secs(5), Duration::from_secs(10)),
+            (Duration::from_millis(10), MIN),
+            (Duration::from_secs(60), MAX),
+            (Duration::MAX, MAX),
+        ] {
+            state.record_attempt_elapsed(elapsed);
+            assert_eq!(RetryBackoff::delay_with_state(&backoff, 2, &state), delay);
+        }
+
+        assert_eq!(RetryBackoff::name(&backoff), "LatencyAware");
+    }
+
+    #[test]
+    fn test_delay() {
+        let backoff = Backoff::new(0.5, MIN, MAX);
+        assert_eq!(RetryBackoff::delay(&backoff, 0), MIN);
+        assert_eq!(RetryBackoff::delay(&backoff, usize::MAX), MIN);
+        assert_eq!(
+            RetryBackoff::try_delay_bounds(&backoff, 1),
+            Some((MIN, MAX))
+        );
+
+        assert_eq!(
+            backoff.delay_for_elapsed(Duration::from_secs(5)),
+            Duration::from_millis(2500)
+        );
+
+        for factor in [-1.0, f64::NAN] {
+            let backoff = Backoff::new(factor, MIN, MAX);
+            assert_eq!(backoff.delay_for_elapsed(Duration::from_secs(5)), MIN);
+        }
+        let backoff = Backoff::new(f64::INFINITY, MIN, MAX);
+        assert_eq!(backoff.delay_for_elapsed(Duration::from_secs(5)), MAX);
+
+        let backoff = Backoff::new(1.0, MAX, MIN);
+        assert_eq!(RetryBackoff::delay(&backoff, 1), MIN);
+        assert_eq!(backoff.delay_for_elapsed(Duration::from_secs(60)), MIN);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let backoff = Backoff::new(2.0, MIN, MAX);
+        let json = serde_json::to_string(&backoff).unwrap();
+        assert_eq!(json, r#"{"factor":2.0,"min":"100ms","max":"30s"}"#);
+        assert_eq!(serde_json::from_str::<Backoff>(&json).unwrap(), backoff);
+    }
+}
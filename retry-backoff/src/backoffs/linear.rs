@@ -0,0 +1,93 @@
+//! `initial + step * (attempts - 1)`, capped at max.
+
+use core::{fmt, time::Duration};
+
+use crate::{retry_backoff::RetryBackoff, utils::nanos_to_duration};
+
+//
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Backoff {
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub initial: Duration,
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub step: Duration,
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub max: Duration,
+}
+
+impl Backoff {
+    pub const fn new(initial: Duration, step: Duration, max: Duration) -> Self {
+        Self { initial, step, max }
+    }
+
+    pub fn delay(&self, attempts: usize) -> Duration {
+        let steps = attempts.saturating_sub(1) as u128;
+        let nanos = self
+            .initial
+            .as_nanos()
+            .saturating_add(self.step.as_nanos().saturating_mul(steps));
+
+        nanos_to_duration(nanos).min(self.max)
+    }
+}
+
+//
+impl RetryBackoff for Backoff {
+    fn delay(&self, attempts: usize) -> Duration {
+        Self::delay(self, attempts)
+    }
+
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        params("initial", &self.initial);
+        params("step", &self.step);
+        params("max", &self.max);
+    }
+
+    fn name(&self) -> &str {
+        "Linear"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay() {
+        let backoff = Backoff::new(
+            Duration::from_secs(1),
+            Duration::from_secs(2),
+            Duration::from_secs(10),
+        );
+
+        for (attempts, secs) in &[
+            (0, 1),
+            (1, 1),
+            (2, 3),
+            (3, 5),
+            (4, 7),
+            (5, 9),
+            (6, 10),
+            (100, 10),
+            (usize::MAX, 10),
+        ] {
+            assert_eq!(backoff.delay(*attempts), Duration::from_secs(*secs));
+        }
+
+        let backoff = Backoff::new(Duration::MAX, Duration::MAX, Duration::MAX);
+        assert_eq!(backoff.delay(usize::MAX), Duration::MAX);
+    }
+
+    #[test]
+    fn test_impl_retry_backoff() {
+        const BACKOFF: Backoff = Backoff::new(
+            Duration::from_millis(100),
+            Duration::from_millis(100),
+            Duration::from_secs(1),
+        );
+
+        assert_eq!(RetryBackoff::delay(&BACKOFF, 3), Duration::from_millis(300));
+        assert_eq!(RetryBackoff::name(&BACKOFF), "Linear");
+    }
+}
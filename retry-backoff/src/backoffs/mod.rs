@@ -5,14 +5,86 @@ mod r#fn;
 #[cfg(feature = "alloc")]
 pub use r#fn::Backoff as FnBackoff;
 
+#[cfg(feature = "alloc")]
+pub mod tiered;
+
+#[cfg(feature = "alloc")]
+pub use tiered::Backoff as TieredBackoff;
+
 //
 pub mod google_cloud_workflows;
 
 pub use google_cloud_workflows::Backoff as GoogleCloudWorkflowsBackoff;
 
+//
+#[cfg(feature = "std")]
+pub mod grpc;
+
+#[cfg(feature = "std")]
+pub use grpc::Backoff as GrpcBackoff;
+
+//
+#[cfg(feature = "std")]
+pub mod kubernetes;
+
+#[cfg(feature = "std")]
+pub use kubernetes::Backoff as KubernetesBackoff;
+
+//
+pub mod exponential;
+
+pub use exponential::Backoff as ExponentialBackoff;
+
+//
+pub mod constant;
+pub mod explicit_schedule;
+pub mod fibonacci;
+pub mod linear;
+
+pub use constant::Backoff as ConstantBackoff;
+pub use explicit_schedule::Backoff as ExplicitScheduleBackoff;
+pub use fibonacci::Backoff as FibonacciBackoff;
+pub use linear::Backoff as LinearBackoff;
+
 //
 #[cfg(feature = "impl_exponential_backoff")]
 pub mod impl_exponential_backoff;
 
 #[cfg(feature = "impl_backoff_rs")]
 pub mod impl_backoff_rs;
+
+#[cfg(feature = "impl_backon")]
+pub mod impl_backon;
+
+#[cfg(feature = "impl_backoff")]
+pub mod impl_backoff;
+
+//
+pub mod jitter;
+
+pub use jitter::{Jitter, JitterMode};
+
+//
+pub mod binary_exponential;
+
+pub use binary_exponential::Backoff as BinaryExponentialBackoff;
+
+//
+pub mod decorrelated_jitter;
+
+pub use decorrelated_jitter::Backoff as DecorrelatedJitterBackoff;
+
+//
+pub mod latency_aware;
+
+pub use latency_aware::Backoff as LatencyAwareBackoff;
+
+//
+pub mod combinators;
+
+pub use combinators::{Cap, Chain, Floor, Offset, Scale};
+
+//
+pub mod retry_after;
+
+pub use retry_after::{RetryAfter, RetryAfterFn, RetryAfterHint};
@@ -0,0 +1,283 @@
+//! Backoffs overridden by a delay hint carried in the params, e.g. an HTTP
+//! [Retry-After](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Retry-After) header.
+//!
+//! Hints are bounded to `[min, max]`, so a server can't make us sleep for an hour.
+
+use core::{fmt, time::Duration};
+
+use crate::{
+    description::Description,
+    error_aware_retry_backoff::ErrorAwareRetryBackoff,
+    retry_backoff::{BackoffState, RetryBackoff},
+};
+
+//
+pub trait RetryAfterHint {
+    fn retry_after(&self) -> Option<Duration>;
+}
+
+fn bound(hint: Duration, min: Duration, max: Duration) -> Duration {
+    hint.max(min).min(max)
+}
+
+//
+/// For params implementing `RetryAfterHint`.
+#[derive(Clone)]
+pub struct RetryAfter<B> {
+    inner: B,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl<B> RetryAfter<B> {
+    pub fn new(inner: B, max: Duration) -> Self {
+        Self {
+            inner,
+            min: Duration::ZERO,
+            max,
+        }
+    }
+
+    pub fn with_min(mut self, min: Duration) -> Self {
+        self.min = min;
+        self
+    }
+
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+}
+
+impl<B> fmt::Debug for RetryAfter<B>
+where
+    B: RetryBackoff,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryAfter")
+            .field("inner", &self.inner.name())
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+impl<B, Params> ErrorAwareRetryBackoff<Params> for RetryAfter<B>
+where
+    B: RetryBackoff,
+    Params: RetryAfterHint,
+{
+    fn delay_for(&self, params: &Params, attempts: usize) -> Duration {
+        match params.retry_after() {
+            Some(hint) => bound(hint, self.min, self.max),
+            None => self.inner.delay(attempts),
+        }
+    }
+
+    fn try_delay_for_with_state(
+        &self,
+        params: &Params,
+        attempts: usize,
+        state: &BackoffState,
+    ) -> Option<Duration> {
+        match params.retry_after() {
+            Some(hint) => Some(bound(hint, self.min, self.max)),
+            None => self.inner.try_delay_with_state(attempts, state),
+        }
+    }
+
+    fn base(&self) -> &dyn RetryBackoff {
+        &self.inner
+    }
+
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        params("inner", &Description::new(&self.inner));
+        params("min", &self.min);
+        params("max", &self.max);
+    }
+
+    fn name(&self) -> &str {
+        "RetryAfter"
+    }
+}
+
+//
+/// For params the hint is read from with a closure.
+#[derive(Clone)]
+pub struct RetryAfterFn<B, F> {
+    inner: B,
+    f: F,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl<B, F> RetryAfterFn<B, F> {
+    pub fn new(inner: B, f: F, max: Duration) -> Self {
+        Self {
+            inner,
+            f,
+            min: Duration::ZERO,
+            max,
+        }
+    }
+
+    pub fn with_min(mut self, min: Duration) -> Self {
+        self.min = min;
+        self
+    }
+
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+}
+
+impl<B, F> fmt::Debug for RetryAfterFn<B, F>
+where
+    B: RetryBackoff,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryAfterFn")
+            .field("inner", &self.inner.name())
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<B, F, Params> ErrorAwareRetryBackoff<Params> for RetryAfterFn<B, F>
+where
+    B: RetryBackoff,
+    F: Fn(&Params) -> Option<Duration>,
+{
+    fn delay_for(&self, params: &Params, attempts: usize) -> Duration {
+        match (self.f)(params) {
+            Some(hint) => bound(hint, self.min, self.max),
+            None => self.inner.delay(attempts),
+        }
+    }
+
+    fn try_delay_for_with_state(
+        &self,
+        params: &Params,
+        attempts: usize,
+        state: &BackoffState,
+    ) -> Option<Duration> {
+        match (self.f)(params) {
+            Some(hint) => Some(bound(hint, self.min, self.max)),
+            None => self.inner.try_delay_with_state(attempts, state),
+        }
+    }
+
+    fn base(&self) -> &dyn RetryBackoff {
+        &self.inner
+    }
+
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        params("inner", &Description::new(&self.inner));
+        params("min", &self.min);
+        params("max", &self.max);
+    }
+
+    fn name(&self) -> &str {
+        "RetryAfterFn"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::backoffs::{ConstantBackoff, ExplicitScheduleBackoff};
+
+    struct Hint(Option<Duration>);
+    impl RetryAfterHint for Hint {
+        fn retry_after(&self) -> Option<Duration> {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let backoff = RetryAfter::new(
+            ConstantBackoff::new(Duration::from_secs(1)),
+            Duration::from_secs(60),
+        )
+        .with_min(Duration::from_millis(100));
+
+        for (hint, dur) in &[
+            (None, Duration::from_secs(1)),
+            (Some(Duration::from_secs(5)), Duration::from_secs(5)),
+            (Some(Duration::from_secs(3600)), Duration::from_secs(60)),
+            (Some(Duration::ZERO), Duration::from_millis(100)),
+        ] {
+            assert_eq!(
+                ErrorAwareRetryBackoff::delay_for(&backoff, &Hint(*hint), 1),
+                *dur
+            );
+        }
+        assert_eq!(
+            ErrorAwareRetryBackoff::<Hint>::base(&backoff).delay(1),
+            Duration::from_secs(1)
+        );
+        assert_eq!(ErrorAwareRetryBackoff::<Hint>::name(&backoff), "RetryAfter");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_description() {
+        use crate::description::ErrorAwareDescription;
+
+        let backoff = RetryAfter::new(
+            ConstantBackoff::new(Duration::from_secs(1)),
+            Duration::from_secs(60),
+        )
+        .with_min(Duration::from_millis(100));
+        assert_eq!(
+            alloc::format!("{:?}", ErrorAwareDescription::<Hint, _>::new(&backoff)),
+            "RetryAfter { inner: Constant { delay: 1s }, min: 100ms, max: 60s }"
+        );
+    }
+
+    #[test]
+    fn test_retry_after_with_exhausted_inner() {
+        let backoff = RetryAfter::new(
+            ExplicitScheduleBackoff::new([Duration::from_secs(1)]),
+            Duration::from_secs(60),
+        );
+        let state = BackoffState::default();
+
+        assert_eq!(
+            backoff.try_delay_for_with_state(&Hint(None), 2, &state),
+            None
+        );
+        assert_eq!(
+            backoff.try_delay_for_with_state(&Hint(Some(Duration::from_secs(5))), 2, &state),
+            Some(Duration::from_secs(5))
+        );
+    }
+
+    #[test]
+    fn test_retry_after_fn() {
+        let backoff = RetryAfterFn::new(
+            ConstantBackoff::new(Duration::from_secs(1)),
+            |secs: &Option<u64>| secs.map(Duration::from_secs),
+            Duration::from_secs(60),
+        );
+
+        assert_eq!(
+            ErrorAwareRetryBackoff::delay_for(&backoff, &None, 1),
+            Duration::from_secs(1)
+        );
+        assert_eq!(
+            ErrorAwareRetryBackoff::delay_for(&backoff, &Some(30), 1),
+            Duration::from_secs(30)
+        );
+        assert_eq!(
+            ErrorAwareRetryBackoff::delay_for(&backoff, &Some(3600), 1),
+            Duration::from_secs(60)
+        );
+        assert_eq!(
+            ErrorAwareRetryBackoff::<Option<u64>>::name(&backoff),
+            "RetryAfterFn"
+        );
+    }
+}
@@ -0,0 +1,197 @@
+//! Segments served in order, each sees its own attempts starting from 1.
+//! The last segment keeps serving the attempts beyond its length.
+
+use alloc::{boxed::Box, vec::Vec};
+use core::{fmt, time::Duration};
+
+use crate::{
+    description::Description,
+    retry_backoff::{BackoffState, RetryBackoff},
+};
+
+//
+#[derive(Debug)]
+pub struct Segment {
+    pub attempts: usize,
+    pub backoff: Box<dyn RetryBackoff + Send + Sync>,
+}
+
+//
+#[derive(Debug, Default)]
+pub struct Backoff {
+    segments: Vec<Segment>,
+}
+
+impl Backoff {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn segment<B>(mut self, attempts: usize, backoff: B) -> Self
+    where
+        B: RetryBackoff + Send + Sync + 'static,
+    {
+        self.segments.push(Segment {
+            attempts,
+            backoff: Box::new(backoff),
+        });
+        self
+    }
+
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    /// returns the segment and the attempts renumbered within it
+    fn locate(&self, attempts: usize) -> Option<(&Segment, usize)> {
+        let mut remaining = attempts;
+        for (i, segment) in self.segments.iter().enumerate() {
+            if remaining <= segment.attempts || i == self.segments.len() - 1 {
+                return Some((segment, remaining));
+            }
+            remaining -= segment.attempts;
+        }
+        None
+    }
+}
+
+//
+impl RetryBackoff for Backoff {
+    fn delay(&self, attempts: usize) -> Duration {
+        self.locate(attempts)
+            .map(|(segment, attempts)| segment.backoff.delay(attempts))
+            .unwrap_or_default()
+    }
+
+    fn delay_with_state(&self, attempts: usize, state: &BackoffState) -> Duration {
+        self.locate(attempts)
+            .map(|(segment, attempts)| segment.backoff.delay_with_state(attempts, state))
+            .unwrap_or_default()
+    }
+
+    fn try_delay_with_state(&self, attempts: usize, state: &BackoffState) -> Option<Duration> {
+        self.locate(attempts)
+            .and_then(|(segment, attempts)| segment.backoff.try_delay_with_state(attempts, state))
+    }
+
+    fn try_delay_bounds(&self, attempts: usize) -> Option<(Duration, Duration)> {
+        self.locate(attempts)
+            .and_then(|(segment, attempts)| segment.backoff.try_delay_bounds(attempts))
+    }
+
+    fn attempt_timeout(&self, attempts: usize) -> Option<Duration> {
+        self.locate(attempts)
+            .and_then(|(segment, attempts)| segment.backoff.attempt_timeout(attempts))
+    }
+
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        for segment in &self.segments {
+            params(
+                "segment",
+                &(segment.attempts, Description::new(&segment.backoff)),
+            );
+        }
+    }
+
+    fn name(&self) -> &str {
+        "Tiered"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::string::ToString as _;
+
+    use crate::{
+        RetryBackoffExt as _,
+        backoffs::{ConstantBackoff, ExponentialBackoff, LinearBackoff},
+    };
+
+    #[test]
+    fn test_impl_retry_backoff() {
+        // fast, then medium, then slow
+        let backoff = Backoff::new()
+            .segment(3, ConstantBackoff::new(Duration::ZERO))
+            .segment(
+                3,
+                LinearBackoff::new(
+                    Duration::from_millis(100),
+                    Duration::from_millis(100),
+                    Duration::from_secs(1),
+                ),
+            )
+            .segment(
+                1,
+                ExponentialBackoff::new(Duration::from_secs(1), 2, Duration::from_secs(10)),
+            );
+
+        for (attempts, millis) in &[
+            (0, 0),
+            (1, 0),
+            (3, 0),
+            (4, 100),
+            (5, 200),
+            (6, 300),
+            (7, 1000),
+            (8, 2000),
+            (9, 4000),
+            (100, 10000),
+            (usize::MAX, 10000),
+        ] {
+            assert_eq!(
+                RetryBackoff::delay(&backoff, *attempts),
+                Duration::from_millis(*millis)
+            );
+        }
+        assert_eq!(RetryBackoff::name(&backoff), "Tiered");
+        assert_eq!(backoff.segments().len(), 3);
+    }
+
+    #[test]
+    fn test_empty() {
+        let backoff = Backoff::new();
+        assert_eq!(RetryBackoff::delay(&backoff, 1), Duration::ZERO);
+        assert_eq!(RetryBackoff::try_delay(&backoff, 1), None);
+    }
+
+    #[test]
+    fn test_try_delay() {
+        use crate::backoffs::ExplicitScheduleBackoff;
+
+        let backoff = Backoff::new()
+            .segment(2, ConstantBackoff::new(Duration::ZERO))
+            .segment(
+                2,
+                ExplicitScheduleBackoff::new([Duration::from_secs(1), Duration::from_secs(5)]),
+            );
+
+        for (attempts, secs) in &[
+            (1, Some(0)),
+            (2, Some(0)),
+            (3, Some(1)),
+            (4, Some(5)),
+            (5, None),
+        ] {
+            assert_eq!(
+                RetryBackoff::try_delay(&backoff, *attempts),
+                secs.map(Duration::from_secs)
+            );
+        }
+    }
+
+    #[test]
+    fn test_debug() {
+        let backoff = Backoff::new().segment(3, ConstantBackoff::new(Duration::ZERO));
+        assert_eq!(
+            alloc::format!("{backoff:?}"),
+            r#"Backoff { segments: [Segment { attempts: 3, backoff: RetryBackoff(Constant { delay: 0ns }) }] }"#
+        );
+
+        assert_eq!(
+            backoff.description().to_string(),
+            "segment=(3, Constant { delay: 0ns })"
+        );
+    }
+}
@@ -0,0 +1,428 @@
+//! Checks every backoff, first-party or adapter, must pass.
+//!
+//! - attempts start from 1, 0 is treated as 1
+//! - no panics for any attempts, including usize::MAX
+//! - delays stay within `try_delay_bounds`, deterministic backoffs have equal bounds
+//! - caps are respected
+//! - bounds never decrease, unless the schedule is user-defined
+//! - once exhausted, stays exhausted
+
+use core::time::Duration;
+
+use crate::retry_backoff::{BackoffState, RetryBackoff};
+
+const LARGE_ATTEMPTS: &[usize] = &[
+    1_000,
+    u32::MAX as usize,
+    usize::MAX / 2,
+    usize::MAX - 1,
+    usize::MAX,
+];
+
+//
+pub(crate) struct Expect {
+    pub(crate) max: Option<Duration>,
+    pub(crate) monotonic: bool,
+}
+
+impl Expect {
+    pub(crate) const MONOTONIC: Self = Self {
+        max: None,
+        monotonic: true,
+    };
+
+    pub(crate) const fn capped_at(max: Duration) -> Self {
+        Self {
+            max: Some(max),
+            monotonic: true,
+        }
+    }
+}
+
+pub(crate) fn assert_conformance<B>(backoff: &B, expect: Expect)
+where
+    B: RetryBackoff + ?Sized,
+{
+    let name = backoff.name();
+
+    assert_eq!(
+        backoff.try_delay_bounds(0),
+        backoff.try_delay_bounds(1),
+        "{name}: attempts 0 is not treated as 1"
+    );
+
+    let mut prev: Option<(Duration, Duration)> = None;
+    let mut exhausted = false;
+    for attempts in (1..=200).chain(LARGE_ATTEMPTS.iter().copied()) {
+        let bounds = backoff.try_delay_bounds(attempts);
+        let delay = backoff.delay(attempts);
+        let try_delay = backoff.try_delay(attempts);
+        let with_state = backoff.try_delay_with_state(attempts, &BackoffState::default());
+
+        if let Some(max) = expect.max {
+            assert!(delay <= max, "{name}: {attempts} {delay:?} above cap");
+        }
+
+        let Some((low, high)) = bounds else {
+            exhausted = true;
+            assert_eq!(try_delay, None, "{name}: {attempts} bounds exhausted only");
+            assert_eq!(with_state, None, "{name}: {attempts} bounds exhausted only");
+            continue;
+        };
+        assert!(!exhausted, "{name}: {attempts} resumed after exhaustion");
+        assert!(low <= high, "{name}: {attempts} {low:?} > {high:?}");
+
+        if let Some(max) = expect.max {
+            assert!(high <= max, "{name}: {attempts} {high:?} above cap");
+        }
+
+        for dur in [Some(delay), try_delay, with_state] {
+            let dur = dur.unwrap_or_else(|| panic!("{name}: {attempts} delay exhausted only"));
+            assert!(
+                dur >= low && dur <= high,
+                "{name}: {attempts} {dur:?} outside {low:?}..={high:?}"
+            );
+        }
+
+        if expect.monotonic
+            && let Some((prev_low, prev_high)) = prev
+        {
+            assert!(
+                low >= prev_low && high >= prev_high,
+                "{name}: {attempts} {low:?}..={high:?} decreased"
+            );
+        }
+        prev = Some((low, high));
+    }
+}
+
+mod tests {
+    use super::*;
+
+    use crate::{
+        RetryBackoffExt as _,
+        backoffs::{
+            ConstantBackoff, ExplicitScheduleBackoff, ExponentialBackoff, FibonacciBackoff,
+            LatencyAwareBackoff, LinearBackoff,
+        },
+    };
+
+    const SECOND: Duration = Duration::from_secs(1);
+    const MINUTE: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn test_first_party() {
+        assert_conformance(&ConstantBackoff::new(SECOND), Expect::capped_at(SECOND));
+        assert_conformance(&ConstantBackoff::new(Duration::ZERO), Expect::MONOTONIC);
+
+        assert_conformance(
+            &LinearBackoff::new(SECOND, SECOND, MINUTE),
+            Expect::capped_at(MINUTE),
+        );
+        assert_conformance(
+            &LinearBackoff::new(Duration::MAX, Duration::MAX, Duration::MAX),
+            Expect::MONOTONIC,
+        );
+
+        assert_conformance(
+            &FibonacciBackoff::new(SECOND, MINUTE),
+            Expect::capped_at(MINUTE),
+        );
+        assert_conformance(
+            &FibonacciBackoff::new(Duration::ZERO, MINUTE),
+            Expect::capped_at(MINUTE),
+        );
+        assert_conformance(
+            &FibonacciBackoff::new(Duration::from_nanos(1), Duration::MAX),
+            Expect::MONOTONIC,
+        );
+
+        assert_conformance(
+            &ExponentialBackoff::new(SECOND, 2, MINUTE),
+            Expect::capped_at(MINUTE),
+        );
+        assert_conformance(
+            &ExponentialBackoff::with_multiplier_milli(SECOND, 1001, Duration::MAX),
+            Expect::MONOTONIC,
+        );
+        assert_conformance(
+            &ExponentialBackoff::new(Duration::MAX, u32::MAX, Duration::MAX),
+            Expect::MONOTONIC,
+        );
+
+        assert_conformance(
+            &ExplicitScheduleBackoff::new([SECOND, MINUTE]),
+            Expect::capped_at(MINUTE),
+        );
+        assert_conformance(
+            &ExplicitScheduleBackoff::new([MINUTE, SECOND, MINUTE]),
+            Expect {
+                max: Some(MINUTE),
+                monotonic: false,
+            },
+        );
+        assert_conformance(
+            &ExplicitScheduleBackoff::new([Duration::ZERO; 0]),
+            Expect::capped_at(Duration::ZERO),
+        );
+
+        assert_conformance(
+            &LinearBackoff::new(SECOND, SECOND, Duration::MAX)
+                .scale(1.5)
+                .add(SECOND)
+                .max_delay(MINUTE)
+                .min_delay(Duration::from_secs(3)),
+            Expect::capped_at(MINUTE),
+        );
+        assert_conformance(
+            &ConstantBackoff::new(Duration::ZERO)
+                .chain(3, ExponentialBackoff::new(SECOND, 2, MINUTE)),
+            Expect::capped_at(MINUTE),
+        );
+
+        assert_conformance(
+            &LatencyAwareBackoff::new(2.0, SECOND, MINUTE),
+            Expect::capped_at(MINUTE),
+        );
+
+        let dyn_backoff: &dyn RetryBackoff = &LinearBackoff::new(SECOND, SECOND, MINUTE);
+        assert_conformance(dyn_backoff, Expect::capped_at(MINUTE));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_google_cloud_workflows() {
+        use crate::backoffs::GoogleCloudWorkflowsBackoff;
+
+        assert_conformance(
+            &GoogleCloudWorkflowsBackoff::default(),
+            Expect::capped_at(MINUTE),
+        );
+        assert_conformance(
+            &GoogleCloudWorkflowsBackoff::new(0.1, f64::MAX, 2.0),
+            Expect::MONOTONIC,
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[cfg(target_has_atomic = "64")]
+    #[test]
+    fn test_grpc() {
+        use crate::{backoffs::GrpcBackoff, random::SeededRandom};
+
+        // jitter may go above max_backoff
+        assert_conformance(
+            &GrpcBackoff::new(SeededRandom::new(1)),
+            Expect::capped_at(Duration::from_secs(144)),
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[cfg(target_has_atomic = "64")]
+    #[test]
+    fn test_kubernetes() {
+        use crate::{backoffs::KubernetesBackoff, random::SeededRandom};
+
+        let backoff = KubernetesBackoff::new(SECOND, 2.0, 0.5, 10, MINUTE, SeededRandom::new(1));
+        assert_conformance(&backoff, Expect::capped_at(MINUTE * 3 / 2));
+        assert_conformance(
+            &backoff.stop_after_steps(),
+            Expect::capped_at(MINUTE * 3 / 2),
+        );
+        assert_conformance(
+            &KubernetesBackoff::new(
+                SECOND,
+                2.0,
+                0.0,
+                i64::MAX,
+                Duration::ZERO,
+                SeededRandom::new(1),
+            ),
+            Expect::MONOTONIC,
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_alloc() {
+        use alloc::boxed::Box;
+
+        use crate::backoffs::{FnBackoff, TieredBackoff};
+
+        assert_conformance(
+            &FnBackoff::from(|attempts: usize| Duration::from_millis(attempts.max(1) as u64)),
+            Expect::MONOTONIC,
+        );
+
+        assert_conformance(
+            &TieredBackoff::new()
+                .segment(3, ConstantBackoff::new(Duration::ZERO))
+                .segment(
+                    5,
+                    LinearBackoff::new(SECOND, SECOND, Duration::from_secs(5)),
+                )
+                .segment(
+                    1,
+                    ExponentialBackoff::new(Duration::from_secs(5), 2, MINUTE),
+                ),
+            Expect::capped_at(MINUTE),
+        );
+        assert_conformance(&TieredBackoff::new(), Expect::MONOTONIC);
+
+        let boxed: Box<dyn RetryBackoff + Send + Sync> =
+            Box::new(ExponentialBackoff::new(SECOND, 3, MINUTE));
+        assert_conformance(&boxed, Expect::capped_at(MINUTE));
+    }
+
+    #[cfg(target_has_atomic = "64")]
+    #[test]
+    fn test_randomized() {
+        use crate::{
+            backoffs::{BinaryExponentialBackoff, DecorrelatedJitterBackoff, Jitter},
+            random::SeededRandom,
+        };
+
+        let exponential = ExponentialBackoff::new(SECOND, 2, MINUTE);
+        assert_conformance(
+            &Jitter::full(exponential, SeededRandom::new(1)),
+            Expect::capped_at(MINUTE),
+        );
+        assert_conformance(
+            &Jitter::equal(exponential, SeededRandom::new(1)),
+            Expect::capped_at(MINUTE),
+        );
+        assert_conformance(
+            &Jitter::proportional(exponential, 0.2, SeededRandom::new(1)).max_delay(MINUTE),
+            Expect::capped_at(MINUTE),
+        );
+        assert_conformance(
+            &Jitter::proportional(
+                ExponentialBackoff::new(Duration::MAX, 2, Duration::MAX),
+                1.0,
+                SeededRandom::new(1),
+            ),
+            Expect::MONOTONIC,
+        );
+
+        assert_conformance(
+            &DecorrelatedJitterBackoff::new(
+                Duration::from_millis(100),
+                MINUTE,
+                SeededRandom::new(1),
+            ),
+            Expect::capped_at(MINUTE),
+        );
+        assert_conformance(
+            &DecorrelatedJitterBackoff::new(Duration::MAX, Duration::MAX, SeededRandom::new(1)),
+            Expect::MONOTONIC,
+        );
+
+        let slot = Duration::from_nanos(51_200);
+        assert_conformance(
+            &BinaryExponentialBackoff::new(slot, 10, SeededRandom::new(1)),
+            Expect::capped_at(slot * 1023),
+        );
+        assert_conformance(
+            &BinaryExponentialBackoff::new(Duration::MAX, u32::MAX, SeededRandom::new(1)),
+            Expect::MONOTONIC,
+        );
+    }
+
+    #[cfg(feature = "impl_exponential_backoff")]
+    #[test]
+    fn test_impl_exponential_backoff() {
+        use crate::backoffs::impl_exponential_backoff::Backoff;
+
+        assert_conformance(
+            &Backoff::new(Duration::from_millis(100), MINUTE),
+            Expect::capped_at(MINUTE),
+        );
+        assert_conformance(
+            &Backoff::new(Duration::from_millis(100), None),
+            Expect::MONOTONIC,
+        );
+
+        let mut backoff = Backoff::new(Duration::from_millis(100), MINUTE);
+        backoff.set_jitter(0.0);
+        backoff.set_max_attempts(5);
+        assert_conformance(&backoff, Expect::capped_at(MINUTE));
+    }
+
+    #[cfg(feature = "impl_backoff_rs")]
+    #[test]
+    fn test_impl_backoff_rs() {
+        use crate::backoffs::impl_backoff_rs::{BoundedBackoff, ExponentialBackoffBuilder};
+
+        // with the widest bounds
+        let backoff = ExponentialBackoffBuilder::default().max(MINUTE).build();
+        assert_conformance(&backoff, Expect::MONOTONIC);
+
+        // unjittered and the default 150ms jitter
+        for jitter in [Duration::ZERO, Duration::from_millis(150)] {
+            let backoff =
+                BoundedBackoff::new(1.75, Duration::from_millis(500), jitter, Some(MINUTE));
+            assert_conformance(&backoff, Expect::capped_at(MINUTE));
+        }
+    }
+
+    #[cfg(feature = "impl_backon")]
+    #[test]
+    fn test_impl_backon() {
+        use crate::backoffs::impl_backon::{ConstantBuilder, ExponentialBuilder, FibonacciBuilder};
+
+        assert_conformance(&ConstantBuilder::default(), Expect::capped_at(SECOND));
+        assert_conformance(&ExponentialBuilder::default(), Expect::capped_at(MINUTE));
+        assert_conformance(
+            &ExponentialBuilder::default().without_max_times(),
+            Expect::capped_at(MINUTE),
+        );
+        assert_conformance(
+            &ExponentialBuilder::default()
+                .without_max_delay()
+                .without_max_times(),
+            Expect::MONOTONIC,
+        );
+        assert_conformance(
+            &ExponentialBuilder::default().with_total_delay(Some(MINUTE)),
+            Expect::capped_at(MINUTE),
+        );
+        // max_delay stops the growth without capping
+        assert_conformance(
+            &FibonacciBuilder::default().without_max_times(),
+            Expect::MONOTONIC,
+        );
+
+        // jitter goes up to twice the delay, Fibonacci adds up to min_delay
+        assert_conformance(
+            &ExponentialBuilder::default()
+                .with_jitter()
+                .without_max_times(),
+            Expect::capped_at(MINUTE * 2),
+        );
+        assert_conformance(
+            &ConstantBuilder::default().with_jitter(),
+            Expect::capped_at(SECOND * 2),
+        );
+        assert_conformance(
+            &FibonacciBuilder::default()
+                .with_jitter()
+                .without_max_times(),
+            Expect::MONOTONIC,
+        );
+    }
+
+    #[cfg(feature = "impl_backoff")]
+    #[test]
+    fn test_impl_backoff() {
+        use crate::backoffs::impl_backoff::{Backoff, ExponentialBackoffBuilder};
+
+        assert_conformance(&Backoff::default(), Expect::MONOTONIC);
+        assert_conformance(
+            &ExponentialBackoffBuilder::new()
+                .with_randomization_factor(0.0)
+                .with_max_interval(MINUTE)
+                .build(),
+            Expect::capped_at(MINUTE),
+        );
+    }
+}
@@ -0,0 +1,191 @@
+//! Structured parameters of a backoff, returned by [`RetryBackoffExt`](crate::RetryBackoffExt).
+
+use core::{fmt, marker::PhantomData};
+
+use crate::{error_aware_retry_backoff::ErrorAwareRetryBackoff, retry_backoff::RetryBackoff};
+
+//
+/// Formats [`RetryBackoff::describe`].
+///
+/// Display is `key=value` pairs separated by spaces, `initial=1s multiplier=1.25 max=60s`.
+/// Debug is a struct named after the backoff, `Exponential { initial: 1s, multiplier: 1.25, max: 60s }`.
+pub struct Description<'a, B>
+where
+    B: ?Sized,
+{
+    backoff: &'a B,
+}
+
+impl<'a, B> Description<'a, B>
+where
+    B: ?Sized,
+{
+    pub fn new(backoff: &'a B) -> Self {
+        Self { backoff }
+    }
+}
+
+impl<B> fmt::Display for Description<'_, B>
+where
+    B: RetryBackoff + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut ret = Ok(());
+        let mut separator = "";
+        self.backoff.describe(&mut |key, value| {
+            if ret.is_ok() {
+                ret = write!(f, "{separator}{key}={value:?}");
+                separator = " ";
+            }
+        });
+        ret
+    }
+}
+
+impl<B> fmt::Debug for Description<'_, B>
+where
+    B: RetryBackoff + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct(self.backoff.name());
+        self.backoff.describe(&mut |key, value| {
+            debug_struct.field(key, value);
+        });
+        debug_struct.finish()
+    }
+}
+
+//
+/// Formats [`ErrorAwareRetryBackoff::describe`], as [`Description`] does.
+pub struct ErrorAwareDescription<'a, Params, B>
+where
+    B: ?Sized,
+{
+    backoff: &'a B,
+    phantom: PhantomData<fn(&Params)>,
+}
+
+impl<'a, Params, B> ErrorAwareDescription<'a, Params, B>
+where
+    B: ?Sized,
+{
+    pub fn new(backoff: &'a B) -> Self {
+        Self {
+            backoff,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<Params, B> fmt::Display for ErrorAwareDescription<'_, Params, B>
+where
+    B: ErrorAwareRetryBackoff<Params> + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut ret = Ok(());
+        let mut separator = "";
+        self.backoff.describe(&mut |key, value| {
+            if ret.is_ok() {
+                ret = write!(f, "{separator}{key}={value:?}");
+                separator = " ";
+            }
+        });
+        ret
+    }
+}
+
+impl<Params, B> fmt::Debug for ErrorAwareDescription<'_, Params, B>
+where
+    B: ErrorAwareRetryBackoff<Params> + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct(self.backoff.name());
+        self.backoff.describe(&mut |key, value| {
+            debug_struct.field(key, value);
+        });
+        debug_struct.finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::{format, string::ToString as _, vec::Vec};
+    use core::time::Duration;
+
+    use crate::{
+        RetryBackoffExt as _,
+        backoffs::{ConstantBackoff, ExponentialBackoff, FnBackoff, LinearBackoff},
+    };
+
+    const EXPONENTIAL: ExponentialBackoff =
+        ExponentialBackoff::new(Duration::from_secs(1), 2, Duration::from_secs(60));
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            EXPONENTIAL.description().to_string(),
+            "initial=1s multiplier=2.0 max=60s"
+        );
+        assert_eq!(
+            FnBackoff::from(|_| Duration::ZERO)
+                .description()
+                .to_string(),
+            ""
+        );
+        assert_eq!(
+            EXPONENTIAL
+                .max_delay(Duration::from_secs(30))
+                .description()
+                .to_string(),
+            "inner=Exponential { initial: 1s, multiplier: 2.0, max: 60s } max=30s"
+        );
+    }
+
+    #[test]
+    fn test_debug() {
+        assert_eq!(
+            format!("{:?}", EXPONENTIAL.description()),
+            "Exponential { initial: 1s, multiplier: 2.0, max: 60s }"
+        );
+
+        let dyn_backoff: &dyn RetryBackoff = &ConstantBackoff::new(Duration::from_millis(100));
+        assert_eq!(
+            format!("{dyn_backoff:?}"),
+            "RetryBackoff(Constant { delay: 100ms })"
+        );
+        let dyn_backoff: &dyn RetryBackoff = &FnBackoff::from(|_| Duration::ZERO);
+        assert_eq!(format!("{dyn_backoff:?}"), "RetryBackoff(Fn)");
+    }
+
+    #[test]
+    fn test_describe() {
+        let backoff = LinearBackoff::new(
+            Duration::from_secs(1),
+            Duration::from_secs(2),
+            Duration::from_secs(10),
+        )
+        .chain(3, EXPONENTIAL);
+
+        let mut params = Vec::new();
+        RetryBackoff::describe(&backoff, &mut |key, value| {
+            params.push((key.to_string(), format!("{value:?}")))
+        });
+        assert_eq!(
+            params,
+            [
+                (
+                    "first".to_string(),
+                    "Linear { initial: 1s, step: 2s, max: 10s }".to_string()
+                ),
+                ("first_attempts".to_string(), "3".to_string()),
+                (
+                    "then".to_string(),
+                    "Exponential { initial: 1s, multiplier: 2.0, max: 60s }".to_string()
+                ),
+            ]
+        );
+    }
+}
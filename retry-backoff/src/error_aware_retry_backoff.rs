@@ -0,0 +1,126 @@
+use core::{fmt, time::Duration};
+
+use crate::{
+    description::ErrorAwareDescription,
+    retry_backoff::{BackoffState, RetryBackoff},
+};
+
+//
+/// A backoff that may also look at the params (usually the error) of the failed attempt.
+/// Every `RetryBackoff` is one, ignoring the params.
+pub trait ErrorAwareRetryBackoff<Params> {
+    /// attempts start from 1
+    fn delay_for(&self, params: &Params, attempts: usize) -> Duration;
+
+    /// returns None once a finite schedule is exhausted
+    fn try_delay_for_with_state(
+        &self,
+        params: &Params,
+        attempts: usize,
+        state: &BackoffState,
+    ) -> Option<Duration> {
+        let _ = state;
+        Some(self.delay_for(params, attempts))
+    }
+
+    /// the backoff used when the params say nothing
+    fn base(&self) -> &dyn RetryBackoff;
+
+    /// reports parameters as `(key, value)` pairs, see [`RetryBackoff::describe`]
+    /// defaults to the base backoff ones, wrappers report their own
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        self.base().describe(params)
+    }
+
+    fn name(&self) -> &str {
+        "_"
+    }
+}
+
+impl<B, Params> ErrorAwareRetryBackoff<Params> for B
+where
+    B: RetryBackoff,
+{
+    fn delay_for(&self, _params: &Params, attempts: usize) -> Duration {
+        self.delay(attempts)
+    }
+
+    fn try_delay_for_with_state(
+        &self,
+        _params: &Params,
+        attempts: usize,
+        state: &BackoffState,
+    ) -> Option<Duration> {
+        self.try_delay_with_state(attempts, state)
+    }
+
+    fn base(&self) -> &dyn RetryBackoff {
+        self
+    }
+
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        RetryBackoff::describe(self, params)
+    }
+
+    fn name(&self) -> &str {
+        RetryBackoff::name(self)
+    }
+}
+
+//
+impl<Params> fmt::Debug for dyn ErrorAwareRetryBackoff<Params> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ErrorAwareRetryBackoff")
+            .field(&ErrorAwareDescription::new(self))
+            .finish()
+    }
+}
+
+impl<Params> fmt::Debug for dyn ErrorAwareRetryBackoff<Params> + Send {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ErrorAwareRetryBackoff")
+            .field(&ErrorAwareDescription::new(self))
+            .finish()
+    }
+}
+
+impl<Params> fmt::Debug for dyn ErrorAwareRetryBackoff<Params> + Send + Sync {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ErrorAwareRetryBackoff")
+            .field(&ErrorAwareDescription::new(self))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::backoffs::ConstantBackoff;
+
+    #[test]
+    fn test_blanket_impl() {
+        let backoff = ConstantBackoff::new(Duration::from_secs(1));
+
+        assert_eq!(
+            ErrorAwareRetryBackoff::delay_for(&backoff, &(), 1),
+            Duration::from_secs(1)
+        );
+        assert_eq!(
+            ErrorAwareRetryBackoff::<()>::base(&backoff).delay(1),
+            Duration::from_secs(1)
+        );
+        assert_eq!(ErrorAwareRetryBackoff::<()>::name(&backoff), "Constant");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_debug() {
+        let backoff = ConstantBackoff::new(Duration::from_secs(1));
+        let backoff: &dyn ErrorAwareRetryBackoff<()> = &backoff;
+        assert_eq!(
+            alloc::format!("{backoff:?}"),
+            "ErrorAwareRetryBackoff(Constant { delay: 1s })"
+        );
+    }
+}
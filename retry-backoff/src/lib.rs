@@ -6,7 +6,45 @@ extern crate alloc;
 //
 pub mod retry_backoff;
 
-pub use self::retry_backoff::RetryBackoff;
+pub use self::retry_backoff::{BackoffState, RetryBackoff};
+
+pub mod retry_backoff_ext;
+
+pub use self::retry_backoff_ext::RetryBackoffExt;
+
+pub mod error_aware_retry_backoff;
+
+pub use self::error_aware_retry_backoff::ErrorAwareRetryBackoff;
+
+pub mod description;
+
+pub use self::description::{Description, ErrorAwareDescription};
+
+pub mod schedule;
+
+pub use self::schedule::{Schedule, ScheduleBounds};
 
 //
 pub mod backoffs;
+
+//
+pub mod random;
+
+//
+mod utils;
+
+#[cfg(test)]
+mod conformance;
+
+//
+#[cfg(feature = "std")]
+pub mod backoff_config;
+
+#[cfg(feature = "std")]
+pub use self::backoff_config::BackoffConfig;
+
+#[cfg(feature = "std")]
+pub mod backoff_dsl;
+
+#[cfg(feature = "std")]
+pub use self::backoff_dsl::ParseError as BackoffParseError;
@@ -0,0 +1,149 @@
+//! Pluggable randomness for jittered backoffs.
+//!
+//! `RetryBackoff::delay` takes `&self`, so sources use interior mutability.
+
+use core::time::Duration;
+
+use crate::utils::nanos_to_duration;
+
+//
+pub trait RandomSource {
+    /// returns a uniformly distributed u64
+    fn next_u64(&self) -> u64;
+
+    /// returns a uniformly distributed Duration in `[low, high]`
+    fn duration_between(&self, low: Duration, high: Duration) -> Duration {
+        if high <= low {
+            return low;
+        }
+
+        let span = (high - low).as_nanos();
+        let random = ((self.next_u64() as u128) << 64) | self.next_u64() as u128;
+        let offset = random % (span + 1);
+
+        low.saturating_add(nanos_to_duration(offset))
+    }
+}
+
+// [Ref](https://prng.di.unimi.it/splitmix64.c)
+const SPLITMIX64_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+fn splitmix64_mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+//
+/// Deterministic SplitMix64 source, for tests and reproducible schedules.
+#[cfg(target_has_atomic = "64")]
+#[derive(Debug)]
+pub struct SeededRandom {
+    state: core::sync::atomic::AtomicU64,
+}
+
+#[cfg(target_has_atomic = "64")]
+impl SeededRandom {
+    pub const fn new(seed: u64) -> Self {
+        Self {
+            state: core::sync::atomic::AtomicU64::new(seed),
+        }
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl Clone for SeededRandom {
+    fn clone(&self) -> Self {
+        Self::new(self.state.load(core::sync::atomic::Ordering::Relaxed))
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl RandomSource for SeededRandom {
+    fn next_u64(&self) -> u64 {
+        let state = self
+            .state
+            .fetch_add(SPLITMIX64_GAMMA, core::sync::atomic::Ordering::Relaxed);
+        splitmix64_mix(state.wrapping_add(SPLITMIX64_GAMMA))
+    }
+}
+
+//
+/// Thread-local SplitMix64 source seeded from `std::collections::hash_map::RandomState`.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StdRandom;
+
+#[cfg(feature = "std")]
+impl RandomSource for StdRandom {
+    fn next_u64(&self) -> u64 {
+        use std::{
+            cell::Cell,
+            hash::{BuildHasher as _, Hasher as _},
+        };
+
+        std::thread_local! {
+            static STATE: Cell<u64> = Cell::new(
+                std::collections::hash_map::RandomState::new()
+                    .build_hasher()
+                    .finish(),
+            );
+        }
+
+        STATE.with(|state| {
+            let next = state.get().wrapping_add(SPLITMIX64_GAMMA);
+            state.set(next);
+            splitmix64_mix(next)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(target_has_atomic = "64")]
+    #[test]
+    fn test_seeded_random() {
+        // Ref https://prng.di.unimi.it/splitmix64.c , seed 1234567
+        let rng = SeededRandom::new(1234567);
+        assert_eq!(rng.next_u64(), 6457827717110365317);
+        assert_eq!(rng.next_u64(), 3203168211198807973);
+
+        let a = SeededRandom::new(42);
+        let b = a.clone();
+        for _ in 0..10 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[cfg(target_has_atomic = "64")]
+    #[test]
+    fn test_duration_between() {
+        let rng = SeededRandom::new(0);
+
+        for _ in 0..100 {
+            let dur = rng.duration_between(Duration::from_millis(100), Duration::from_millis(200));
+            assert!(dur >= Duration::from_millis(100) && dur <= Duration::from_millis(200));
+        }
+        assert_eq!(
+            rng.duration_between(Duration::from_secs(1), Duration::from_secs(1)),
+            Duration::from_secs(1)
+        );
+        assert_eq!(
+            rng.duration_between(Duration::from_secs(2), Duration::from_secs(1)),
+            Duration::from_secs(2)
+        );
+        let dur = rng.duration_between(Duration::ZERO, Duration::MAX);
+        assert!(dur <= Duration::MAX);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_std_random() {
+        let rng = StdRandom;
+        let dur = rng.duration_between(Duration::ZERO, Duration::from_secs(1));
+        assert!(dur <= Duration::from_secs(1));
+        assert_ne!(rng.next_u64(), rng.next_u64());
+    }
+}
@@ -1,20 +1,150 @@
 use core::{fmt, time::Duration};
 
+use crate::description::Description;
+
 //
 pub trait RetryBackoff {
-    /// attempts start from 1
+    /// attempts start from 1, 0 is treated as 1
+    /// must not panic for any attempts, see the conformance checks
     fn delay(&self, attempts: usize) -> Duration;
 
+    /// attempts start from 1
+    /// state is kept by the caller for the duration of one retry invocation
+    fn delay_with_state(&self, attempts: usize, state: &BackoffState) -> Duration {
+        let _ = state;
+        self.delay(attempts)
+    }
+
+    /// returns None once a finite schedule is exhausted
+    /// exhaustible backoffs override this one, wrappers forward it
+    fn try_delay_with_state(&self, attempts: usize, state: &BackoffState) -> Option<Duration> {
+        Some(self.delay_with_state(attempts, state))
+    }
+
+    /// returns None once a finite schedule is exhausted
+    fn try_delay(&self, attempts: usize) -> Option<Duration> {
+        self.try_delay_with_state(attempts, &BackoffState::default())
+    }
+
+    /// returns the `(min, max)` range the delay for attempts is drawn from, None once exhausted
+    /// randomized backoffs override this one, wrappers forward it
+    fn try_delay_bounds(&self, attempts: usize) -> Option<(Duration, Duration)> {
+        self.try_delay(attempts).map(|delay| (delay, delay))
+    }
+
+    /// a timeout for attempts, counted from 1 for the first call, None for no opinion
+    /// e.g. gRPC bounds each connect attempt, wrappers forward it
+    fn attempt_timeout(&self, attempts: usize) -> Option<Duration> {
+        let _ = attempts;
+        None
+    }
+
+    /// reports parameters as `(key, value)` pairs, see [`Description`](crate::Description)
+    /// nested backoffs are reported as their `Description`
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        let _ = params;
+    }
+
     fn name(&self) -> &str {
         "_"
     }
 }
 
+//
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BackoffState {
+    pub prev_delay: Option<Duration>,
+    /// how long the failed attempt took, when the caller measures it
+    pub last_attempt_elapsed: Option<Duration>,
+}
+
+impl BackoffState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, delay: Duration) {
+        self.prev_delay = Some(delay);
+    }
+
+    pub fn record_attempt_elapsed(&mut self, elapsed: Duration) {
+        self.last_attempt_elapsed = Some(elapsed);
+    }
+}
+
+//
+impl<B> RetryBackoff for &B
+where
+    B: RetryBackoff + ?Sized,
+{
+    fn delay(&self, attempts: usize) -> Duration {
+        (**self).delay(attempts)
+    }
+
+    fn delay_with_state(&self, attempts: usize, state: &BackoffState) -> Duration {
+        (**self).delay_with_state(attempts, state)
+    }
+
+    fn try_delay_with_state(&self, attempts: usize, state: &BackoffState) -> Option<Duration> {
+        (**self).try_delay_with_state(attempts, state)
+    }
+
+    fn try_delay_bounds(&self, attempts: usize) -> Option<(Duration, Duration)> {
+        (**self).try_delay_bounds(attempts)
+    }
+
+    fn attempt_timeout(&self, attempts: usize) -> Option<Duration> {
+        (**self).attempt_timeout(attempts)
+    }
+
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        (**self).describe(params)
+    }
+
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B> RetryBackoff for alloc::boxed::Box<B>
+where
+    B: RetryBackoff + ?Sized,
+{
+    fn delay(&self, attempts: usize) -> Duration {
+        (**self).delay(attempts)
+    }
+
+    fn delay_with_state(&self, attempts: usize, state: &BackoffState) -> Duration {
+        (**self).delay_with_state(attempts, state)
+    }
+
+    fn try_delay_with_state(&self, attempts: usize, state: &BackoffState) -> Option<Duration> {
+        (**self).try_delay_with_state(attempts, state)
+    }
+
+    fn try_delay_bounds(&self, attempts: usize) -> Option<(Duration, Duration)> {
+        (**self).try_delay_bounds(attempts)
+    }
+
+    fn attempt_timeout(&self, attempts: usize) -> Option<Duration> {
+        (**self).attempt_timeout(attempts)
+    }
+
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        (**self).describe(params)
+    }
+
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+}
+
 //
 impl fmt::Debug for dyn RetryBackoff {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("RetryBackoff")
-            .field(&RetryBackoff::name(self))
+            .field(&Description::new(self))
             .finish()
     }
 }
@@ -22,7 +152,7 @@ impl fmt::Debug for dyn RetryBackoff {
 impl fmt::Debug for dyn RetryBackoff + Send {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("RetryBackoff")
-            .field(&RetryBackoff::name(self))
+            .field(&Description::new(self))
             .finish()
     }
 }
@@ -30,7 +160,7 @@ impl fmt::Debug for dyn RetryBackoff + Send {
 impl fmt::Debug for dyn RetryBackoff + Send + Sync {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("RetryBackoff")
-            .field(&RetryBackoff::name(self))
+            .field(&Description::new(self))
             .finish()
     }
 }
@@ -0,0 +1,160 @@
+use core::time::Duration;
+
+use crate::{
+    backoffs::combinators::{Cap, Chain, Floor, Offset, Scale},
+    description::Description,
+    retry_backoff::RetryBackoff,
+    schedule::{Schedule, ScheduleBounds},
+    utils::nanos_to_duration,
+};
+
+/// attempts summed one by one by the totals
+/// later ones repeat the delay of this attempt when the bounds stay flat up to the last attempt,
+/// otherwise the delay of the last attempt, an upper bound for schedules that do not shrink
+pub const SUMMED_ATTEMPTS: usize = 1_000;
+
+//
+pub trait RetryBackoffExt: RetryBackoff {
+    fn max_delay(self, max: Duration) -> Cap<Self>
+    where
+        Self: Sized,
+    {
+        Cap::new(self, max)
+    }
+
+    fn min_delay(self, min: Duration) -> Floor<Self>
+    where
+        Self: Sized,
+    {
+        Floor::new(self, min)
+    }
+
+    fn scale(self, factor: f64) -> Scale<Self>
+    where
+        Self: Sized,
+    {
+        Scale::new(self, factor)
+    }
+
+    fn add(self, offset: Duration) -> Offset<Self>
+    where
+        Self: Sized,
+    {
+        Offset::new(self, offset)
+    }
+
+    /// first `attempts` attempts from self, then from next renumbered from 1
+    fn chain<B>(self, attempts: usize, next: B) -> Chain<Self, B>
+    where
+        Self: Sized,
+        B: RetryBackoff,
+    {
+        Chain::new(self, attempts, next)
+    }
+
+    /// `initial=1s multiplier=2.0 max=60s` with Display, `Exponential { initial: 1s, .. }` with Debug
+    fn description(&self) -> Description<'_, Self> {
+        Description::new(self)
+    }
+
+    /// delays for attempts `1..=max_attempts`
+    /// randomized backoffs yield one sample, see `schedule_bounds` for the range
+    fn schedule(&self, max_attempts: usize) -> Schedule<'_, Self> {
+        Schedule::new(self, max_attempts)
+    }
+
+    /// `(min, max)` delays for attempts `1..=max_attempts`
+    fn schedule_bounds(&self, max_attempts: usize) -> ScheduleBounds<'_, Self> {
+        ScheduleBounds::new(self, max_attempts)
+    }
+
+    /// total of one `schedule`, saturating
+    /// past [`SUMMED_ATTEMPTS`], an estimate that may exceed the sampled total of a growing schedule
+    fn cumulative_delay(&self, max_attempts: usize) -> Duration {
+        saturating_sum(
+            self,
+            max_attempts,
+            self.schedule(max_attempts).map(|(_, delay)| delay),
+            |last, _| Some(self.delay(last)),
+        )
+    }
+
+    /// shortest total wait over `max_attempts` retries, saturating
+    /// past [`SUMMED_ATTEMPTS`], a lower bound while the schedule still grows
+    fn min_total_delay(&self, max_attempts: usize) -> Duration {
+        saturating_sum(
+            self,
+            max_attempts,
+            self.schedule_bounds(max_attempts).map(|(_, (min, _))| min),
+            |_, _| None,
+        )
+    }
+
+    /// worst-case total wait over `max_attempts` retries, saturating
+    /// past [`SUMMED_ATTEMPTS`], an upper bound while the schedule still grows
+    fn max_total_delay(&self, max_attempts: usize) -> Duration {
+        saturating_sum(
+            self,
+            max_attempts,
+            self.schedule_bounds(max_attempts).map(|(_, (_, max))| max),
+            |_, (_, max)| Some(max),
+        )
+    }
+}
+
+/// `tail` gets the last attempts and their bounds when these differ from the ones at [`SUMMED_ATTEMPTS`],
+/// and returns the delay repeated by the remaining attempts, None to repeat the last summed one
+fn saturating_sum<B>(
+    backoff: &B,
+    max_attempts: usize,
+    delays: impl Iterator<Item = Duration>,
+    tail: impl FnOnce(usize, (Duration, Duration)) -> Option<Duration>,
+) -> Duration
+where
+    B: RetryBackoff + ?Sized,
+{
+    let mut total = Duration::ZERO;
+    let mut summed = 0;
+    let mut last = Duration::ZERO;
+    for delay in delays.take(SUMMED_ATTEMPTS) {
+        total = total.saturating_add(delay);
+        summed += 1;
+        last = delay;
+    }
+    if summed < SUMMED_ATTEMPTS || max_attempts <= SUMMED_ATTEMPTS {
+        return total;
+    }
+
+    let last_attempts = last_available(backoff, SUMMED_ATTEMPTS, max_attempts);
+    let remaining = last_attempts - SUMMED_ATTEMPTS;
+    let delay = match backoff.try_delay_bounds(last_attempts) {
+        Some(bounds) if Some(bounds) != backoff.try_delay_bounds(SUMMED_ATTEMPTS) => {
+            tail(last_attempts, bounds).unwrap_or(last)
+        }
+        _ => last,
+    };
+    total.saturating_add(nanos_to_duration(
+        delay.as_nanos().saturating_mul(remaining as u128),
+    ))
+}
+
+/// the last attempts in `low..=high` not exhausted, given low is not, as exhaustion is permanent
+fn last_available<B>(backoff: &B, mut low: usize, mut high: usize) -> usize
+where
+    B: RetryBackoff + ?Sized,
+{
+    if backoff.try_delay_bounds(high).is_some() {
+        return high;
+    }
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        if backoff.try_delay_bounds(mid).is_some() {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+impl<B> RetryBackoffExt for B where B: RetryBackoff + ?Sized {}
@@ -0,0 +1,263 @@
+//! Iterators over the delays of a backoff, returned by [`RetryBackoffExt`](crate::RetryBackoffExt).
+
+use core::{fmt, iter::FusedIterator, time::Duration};
+
+use crate::retry_backoff::{BackoffState, RetryBackoff};
+
+//
+/// `(attempts, delay)` for attempts `1..=max_attempts`, ends early once the backoff is exhausted.
+///
+/// State is threaded through like a retry invocation does, so stateful backoffs yield a realistic chain.
+pub struct Schedule<'a, B>
+where
+    B: ?Sized,
+{
+    backoff: &'a B,
+    attempts: usize,
+    max_attempts: usize,
+    state: BackoffState,
+}
+
+impl<'a, B> Schedule<'a, B>
+where
+    B: ?Sized,
+{
+    pub fn new(backoff: &'a B, max_attempts: usize) -> Self {
+        Self {
+            backoff,
+            attempts: 0,
+            max_attempts,
+            state: BackoffState::default(),
+        }
+    }
+}
+
+impl<B> fmt::Debug for Schedule<'_, B>
+where
+    B: RetryBackoff + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Schedule")
+            .field("backoff", &self.backoff.name())
+            .field("attempts", &self.attempts)
+            .field("max_attempts", &self.max_attempts)
+            .finish()
+    }
+}
+
+impl<B> Iterator for Schedule<'_, B>
+where
+    B: RetryBackoff + ?Sized,
+{
+    type Item = (usize, Duration);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.attempts >= self.max_attempts {
+            return None;
+        }
+        self.attempts += 1;
+
+        match self
+            .backoff
+            .try_delay_with_state(self.attempts, &self.state)
+        {
+            Some(delay) => {
+                self.state.record(delay);
+                Some((self.attempts, delay))
+            }
+            None => {
+                self.max_attempts = self.attempts;
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.max_attempts - self.attempts))
+    }
+}
+
+impl<B> FusedIterator for Schedule<'_, B> where B: RetryBackoff + ?Sized {}
+
+//
+/// `(attempts, (min, max))` for attempts `1..=max_attempts`, ends early once the backoff is exhausted.
+pub struct ScheduleBounds<'a, B>
+where
+    B: ?Sized,
+{
+    backoff: &'a B,
+    attempts: usize,
+    max_attempts: usize,
+}
+
+impl<'a, B> ScheduleBounds<'a, B>
+where
+    B: ?Sized,
+{
+    pub fn new(backoff: &'a B, max_attempts: usize) -> Self {
+        Self {
+            backoff,
+            attempts: 0,
+            max_attempts,
+        }
+    }
+}
+
+impl<B> fmt::Debug for ScheduleBounds<'_, B>
+where
+    B: RetryBackoff + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScheduleBounds")
+            .field("backoff", &self.backoff.name())
+            .field("attempts", &self.attempts)
+            .field("max_attempts", &self.max_attempts)
+            .finish()
+    }
+}
+
+impl<B> Iterator for ScheduleBounds<'_, B>
+where
+    B: RetryBackoff + ?Sized,
+{
+    type Item = (usize, (Duration, Duration));
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.attempts >= self.max_attempts {
+            return None;
+        }
+        self.attempts += 1;
+
+        match self.backoff.try_delay_bounds(self.attempts) {
+            Some(bounds) => Some((self.attempts, bounds)),
+            None => {
+                self.max_attempts = self.attempts;
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.max_attempts - self.attempts))
+    }
+}
+
+impl<B> FusedIterator for ScheduleBounds<'_, B> where B: RetryBackoff + ?Sized {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        RetryBackoffExt as _,
+        backoffs::{ExplicitScheduleBackoff, ExponentialBackoff},
+    };
+
+    const EXPONENTIAL: ExponentialBackoff =
+        ExponentialBackoff::new(Duration::from_millis(100), 2, Duration::from_secs(1));
+
+    #[test]
+    fn test_schedule() {
+        let mut schedule = EXPONENTIAL.schedule(6);
+        for (attempts, millis) in [(1, 100), (2, 200), (3, 400), (4, 800), (5, 1000), (6, 1000)] {
+            assert_eq!(
+                schedule.next(),
+                Some((attempts, Duration::from_millis(millis)))
+            );
+        }
+        assert_eq!(schedule.next(), None);
+        assert_eq!(schedule.next(), None);
+
+        assert_eq!(EXPONENTIAL.schedule(0).next(), None);
+
+        const SCHEDULE: ExplicitScheduleBackoff<[Duration; 2]> =
+            ExplicitScheduleBackoff::new([Duration::from_secs(1), Duration::from_secs(5)]);
+        let mut schedule = SCHEDULE.schedule(usize::MAX);
+        assert_eq!(schedule.next(), Some((1, Duration::from_secs(1))));
+        assert_eq!(schedule.next(), Some((2, Duration::from_secs(5))));
+        assert_eq!(schedule.next(), None);
+        assert_eq!(schedule.next(), None);
+        assert_eq!(schedule.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn test_schedule_bounds() {
+        assert!(
+            EXPONENTIAL
+                .schedule_bounds(3)
+                .eq(EXPONENTIAL.schedule(3).map(|(a, d)| (a, (d, d))))
+        );
+
+        let dyn_backoff: &dyn RetryBackoff = &EXPONENTIAL;
+        assert_eq!(
+            dyn_backoff.schedule_bounds(2).last(),
+            Some((2, (Duration::from_millis(200), Duration::from_millis(200))))
+        );
+    }
+
+    #[test]
+    fn test_total_delay() {
+        assert_eq!(EXPONENTIAL.cumulative_delay(6), Duration::from_millis(3500));
+        assert_eq!(EXPONENTIAL.min_total_delay(6), Duration::from_millis(3500));
+        assert_eq!(EXPONENTIAL.max_total_delay(6), Duration::from_millis(3500));
+        assert_eq!(EXPONENTIAL.cumulative_delay(0), Duration::ZERO);
+
+        let backoff = ExponentialBackoff::new(Duration::MAX, 2, Duration::MAX);
+        assert_eq!(backoff.max_total_delay(usize::MAX), Duration::MAX);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_total_delay_many_attempts() {
+        use crate::{
+            backoffs::{ConstantBackoff, ExplicitScheduleBackoff, LinearBackoff},
+            retry_backoff_ext::SUMMED_ATTEMPTS,
+        };
+
+        // returns without summing every attempt
+        let backoff = ConstantBackoff::new(Duration::from_millis(1));
+        assert_eq!(
+            backoff.max_total_delay(usize::MAX),
+            Duration::from_millis(usize::MAX as u64)
+        );
+        assert_eq!(
+            backoff.cumulative_delay(SUMMED_ATTEMPTS * 3),
+            Duration::from_millis(3000)
+        );
+
+        // stops where an explicit schedule is exhausted
+        let backoff = ExplicitScheduleBackoff::new(alloc::vec![Duration::from_millis(1); 2500]);
+        assert_eq!(
+            backoff.max_total_delay(usize::MAX),
+            Duration::from_millis(2500)
+        );
+        assert_eq!(backoff.max_total_delay(2000), Duration::from_millis(2000));
+
+        // flat once capped, exact
+        let backoff = LinearBackoff::new(
+            Duration::from_millis(1),
+            Duration::from_millis(1),
+            Duration::from_millis(500),
+        );
+        assert_eq!(
+            backoff.max_total_delay(2000),
+            Duration::from_millis(500 * 501 / 2 + 1500 * 500)
+        );
+
+        // still growing, bounds around the exact 2001s
+        let backoff = LinearBackoff::new(
+            Duration::from_millis(1),
+            Duration::from_millis(1),
+            Duration::MAX,
+        );
+        assert_eq!(
+            backoff.max_total_delay(2000),
+            Duration::from_millis(1000 * 1001 / 2 + 1000 * 2000)
+        );
+        assert_eq!(
+            backoff.min_total_delay(2000),
+            Duration::from_millis(1000 * 1001 / 2 + 1000 * 1000)
+        );
+        assert_eq!(backoff.max_total_delay(usize::MAX), Duration::MAX);
+    }
+}
@@ -0,0 +1,46 @@
+use core::time::Duration;
+
+//
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// too large is Duration::MAX
+pub(crate) fn nanos_to_duration(nanos: u128) -> Duration {
+    match u64::try_from(nanos / NANOS_PER_SEC) {
+        Ok(secs) => Duration::new(secs, (nanos % NANOS_PER_SEC) as u32),
+        Err(_) => Duration::MAX,
+    }
+}
+
+/// negative and NaN are zero, too large is Duration::MAX
+pub(crate) fn secs_f64_to_duration(secs: f64) -> Duration {
+    Duration::try_from_secs_f64(secs).unwrap_or(if secs > 0.0 {
+        Duration::MAX
+    } else {
+        Duration::ZERO
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nanos_to_duration() {
+        assert_eq!(nanos_to_duration(0), Duration::ZERO);
+        assert_eq!(
+            nanos_to_duration(1_500_000_000),
+            Duration::from_millis(1500)
+        );
+        assert_eq!(nanos_to_duration(Duration::MAX.as_nanos()), Duration::MAX);
+        assert_eq!(nanos_to_duration(u128::MAX), Duration::MAX);
+    }
+
+    #[test]
+    fn test_secs_f64_to_duration() {
+        assert_eq!(secs_f64_to_duration(1.5), Duration::from_millis(1500));
+        assert_eq!(secs_f64_to_duration(-1.0), Duration::ZERO);
+        assert_eq!(secs_f64_to_duration(f64::NAN), Duration::ZERO);
+        assert_eq!(secs_f64_to_duration(f64::INFINITY), Duration::MAX);
+        assert_eq!(secs_f64_to_duration(1e30), Duration::MAX);
+    }
+}
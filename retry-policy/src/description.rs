@@ -0,0 +1,138 @@
+//! Structured parameters of a policy, for logs and telemetry.
+
+use core::{fmt, marker::PhantomData};
+
+use crate::retry_policy::RetryPolicy;
+
+//
+/// Formats [`RetryPolicy::describe`].
+///
+/// Display is `key=value` pairs separated by spaces,
+/// `max_retries=3 predicate=Always backoff=Exponential { initial: 1s, multiplier: 2.0, max: 60s }`.
+/// Debug is a struct named after the policy.
+pub struct Description<'a, PParams, POL>
+where
+    POL: ?Sized,
+{
+    policy: &'a POL,
+    phantom: PhantomData<fn(&PParams)>,
+}
+
+impl<'a, PParams, POL> Description<'a, PParams, POL>
+where
+    POL: RetryPolicy<PParams> + ?Sized,
+{
+    pub fn new(policy: &'a POL) -> Self {
+        Self {
+            policy,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<PParams, POL> fmt::Display for Description<'_, PParams, POL>
+where
+    POL: RetryPolicy<PParams> + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut ret = Ok(());
+        let mut separator = "";
+        self.policy.describe(&mut |key, value| {
+            if ret.is_ok() {
+                ret = write!(f, "{separator}{key}={value:?}");
+                separator = " ";
+            }
+        });
+        ret
+    }
+}
+
+impl<PParams, POL> fmt::Debug for Description<'_, PParams, POL>
+where
+    POL: RetryPolicy<PParams> + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct(self.policy.name());
+        self.policy.describe(&mut |key, value| {
+            debug_struct.field(key, value);
+        });
+        debug_struct.finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::{
+        format,
+        string::{String, ToString as _},
+        vec::Vec,
+    };
+    use core::time::Duration;
+
+    use retry_backoff::backoffs::ExponentialBackoff;
+    use retry_predicate::predicates::AlwaysPredicate;
+
+    use crate::policies::{FnPolicy, SimplePolicy};
+
+    #[test]
+    fn test_simple_policy() {
+        let policy = SimplePolicy::new(
+            AlwaysPredicate,
+            3,
+            ExponentialBackoff::new(Duration::from_secs(1), 2, Duration::from_secs(60)),
+        );
+
+        assert_eq!(
+            Description::<(), _>::new(&policy).to_string(),
+            "max_retries=3 predicate=Always backoff=Exponential { initial: 1s, multiplier: 2.0, max: 60s }"
+        );
+
+        let dyn_policy: &dyn RetryPolicy<()> = &policy;
+        assert_eq!(
+            format!("{dyn_policy:?}"),
+            "RetryPolicy(Simple { max_retries: 3, predicate: Always, backoff: Exponential { initial: 1s, multiplier: 2.0, max: 60s } })"
+        );
+
+        // as telemetry attributes
+        let mut attributes: Vec<(String, String)> = Vec::new();
+        dyn_policy.describe(&mut |key, value| {
+            attributes.push((format!("retry.{key}"), format!("{value:?}")))
+        });
+        assert_eq!(
+            attributes
+                .iter()
+                .map(|(key, _)| key.as_str())
+                .collect::<Vec<_>>(),
+            ["retry.max_retries", "retry.predicate", "retry.backoff"]
+        );
+    }
+
+    #[test]
+    fn test_fn_policy() {
+        let policy =
+            FnPolicy::<()>::from(|_: &(), _| core::ops::ControlFlow::Continue(Duration::ZERO));
+        assert_eq!(Description::new(&policy).to_string(), "");
+        assert_eq!(format!("{:?}", Description::new(&policy)), "Fn");
+
+        // the accessors are unreachable, Debug does not call them
+        let dyn_policy: &dyn RetryPolicy<()> = &policy;
+        assert_eq!(format!("{dyn_policy:?}"), "RetryPolicy(Fn)");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_google_cloud_workflows_policy() {
+        use retry_backoff::backoffs::google_cloud_workflows::Backoff;
+
+        use crate::policies::google_cloud_workflows::Policy;
+
+        let policy = Policy::new(AlwaysPredicate, 5, Backoff::default());
+        assert_eq!(
+            Description::<(), _>::new(&policy).to_string(),
+            "max_retries=5 predicate=Always backoff=GoogleCloudWorkflows { initial: 1s, multiplier: 1.25, max: 60s }"
+        );
+    }
+}
@@ -12,5 +12,9 @@ mod retry_policy;
 
 pub use self::retry_policy::{RetryPolicy, StopReason};
 
+pub mod description;
+
+pub use self::description::Description;
+
 //
 pub mod policies;
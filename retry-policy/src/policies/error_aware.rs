@@ -0,0 +1,125 @@
+use alloc::boxed::Box;
+use core::{fmt, ops::ControlFlow, time::Duration};
+
+use retry_backoff::{BackoffState, ErrorAwareDescription, ErrorAwareRetryBackoff, RetryBackoff};
+use retry_predicate::RetryPredicate;
+
+use crate::retry_policy::{RetryPolicy, StopReason};
+
+//
+#[derive(Debug)]
+pub struct Policy<PParams> {
+    pub predicate: Box<dyn RetryPredicate<PParams> + Send + Sync>,
+    pub max_retries: usize,
+    pub backoff: Box<dyn ErrorAwareRetryBackoff<PParams> + Send + Sync>,
+}
+
+impl<PParams> Policy<PParams> {
+    pub fn new<P, BO>(predicate: P, max_retries: usize, backoff: BO) -> Self
+    where
+        P: RetryPredicate<PParams> + Send + Sync + 'static,
+        BO: ErrorAwareRetryBackoff<PParams> + Send + Sync + 'static,
+    {
+        Self {
+            predicate: Box::new(predicate),
+            max_retries,
+            backoff: Box::new(backoff),
+        }
+    }
+}
+
+impl<PParams> RetryPolicy<PParams> for Policy<PParams> {
+    fn predicate(&self) -> &dyn RetryPredicate<PParams> {
+        self.predicate.as_ref()
+    }
+    fn max_retries(&self) -> usize {
+        self.max_retries
+    }
+    fn backoff(&self) -> &dyn RetryBackoff {
+        self.backoff.base()
+    }
+
+    fn next_step_with_state(
+        &self,
+        params: &PParams,
+        attempts: usize,
+        state: &mut BackoffState,
+    ) -> ControlFlow<StopReason, Duration> {
+        self.next_step_from_parts(params, attempts, state)
+    }
+
+    fn delay_for(
+        &self,
+        params: &PParams,
+        attempts: usize,
+        state: &BackoffState,
+    ) -> Option<Duration> {
+        self.backoff
+            .try_delay_for_with_state(params, attempts, state)
+    }
+
+    fn attempt_timeout(&self, attempts: usize) -> Option<Duration> {
+        self.backoff.base().attempt_timeout(attempts)
+    }
+
+    /// reports the error-aware backoff, not only its base
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        params("max_retries", &self.max_retries);
+        params("predicate", &format_args!("{}", self.predicate.name()));
+        params(
+            "backoff",
+            &ErrorAwareDescription::new(self.backoff.as_ref()),
+        );
+    }
+
+    fn name(&self) -> &str {
+        "ErrorAware"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::string::ToString as _;
+
+    use retry_backoff::backoffs::{ConstantBackoff, RetryAfterFn};
+    use retry_predicate::predicates::AlwaysPredicate;
+
+    #[test]
+    fn test_impl_retry_policy() {
+        let policy = Policy::new(
+            AlwaysPredicate,
+            2,
+            RetryAfterFn::new(
+                ConstantBackoff::new(Duration::from_secs(1)),
+                |secs: &Option<u64>| secs.map(Duration::from_secs),
+                Duration::from_secs(10),
+            ),
+        );
+
+        assert_eq!(
+            RetryPolicy::next_step(&policy, &None, 1),
+            ControlFlow::Continue(Duration::from_secs(1))
+        );
+        assert_eq!(
+            RetryPolicy::next_step(&policy, &Some(5), 1),
+            ControlFlow::Continue(Duration::from_secs(5))
+        );
+        assert_eq!(
+            RetryPolicy::next_step(&policy, &Some(60), 2),
+            ControlFlow::Continue(Duration::from_secs(10))
+        );
+        assert_eq!(
+            RetryPolicy::next_step(&policy, &Some(5), 3),
+            ControlFlow::Break(crate::retry_policy::StopReason::MaxRetriesReached)
+        );
+        assert_eq!(RetryPolicy::backoff(&policy).name(), "Constant");
+        assert_eq!(RetryPolicy::name(&policy), "ErrorAware");
+
+        assert_eq!(
+            crate::Description::new(&policy).to_string(),
+            "max_retries=2 predicate=Always backoff=RetryAfterFn { inner: Constant { delay: 1s }, min: 0ns, max: 10s }"
+        );
+    }
+}
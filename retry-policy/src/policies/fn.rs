@@ -46,6 +46,9 @@ where
         (self.f)(params, attempts)
     }
 
+    /// the accessors are unreachable, reports nothing
+    fn describe(&self, _params: &mut dyn FnMut(&str, &dyn core::fmt::Debug)) {}
+
     fn name(&self) -> &str {
         "Fn"
     }
@@ -61,6 +64,8 @@ fn fn_demo(_params: &(), _attempts: usize) -> ControlFlow<StopReason, Duration>
 mod tests {
     use super::*;
 
+    use retry_backoff::BackoffState;
+
     #[test]
     fn test_from_f() {
         let _ = Policy::from(fn_demo);
@@ -77,6 +82,10 @@ mod tests {
             RetryPolicy::next_step(&policy, &(), 1),
             ControlFlow::Continue(Duration::from_secs(1))
         );
+        assert_eq!(
+            RetryPolicy::next_step_with_state(&policy, &(), 1, &mut BackoffState::default()),
+            ControlFlow::Continue(Duration::from_secs(1))
+        );
         assert_eq!(RetryPolicy::name(&policy), "Fn");
     }
 }
@@ -1,4 +1,6 @@
-use retry_backoff::backoffs::google_cloud_workflows::Backoff;
+use core::time::Duration;
+
+use retry_backoff::backoffs::{RetryAfterHint, google_cloud_workflows::Backoff};
 use retry_predicate::predicates::FnPredicate;
 
 use super::Policy;
@@ -79,6 +81,20 @@ impl core::fmt::Display for Error {
 
 impl core::error::Error for Error {}
 
+impl RetryAfterHint for Error {
+    fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Error::TooManyRequests {
+                retry_after_delay_seconds,
+            }
+            | Error::ServiceUnavailable {
+                retry_after_delay_seconds,
+            } => retry_after_delay_seconds.map(|secs| Duration::from_secs(secs as u64)),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -134,6 +150,80 @@ mod tests {
         assert_eq!(policy.backoff, Backoff::default());
     }
 
+    #[test]
+    fn test_error_retry_after_hint() {
+        assert_eq!(
+            Error::TooManyRequests {
+                retry_after_delay_seconds: Some(5)
+            }
+            .retry_after(),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            Error::ServiceUnavailable {
+                retry_after_delay_seconds: Some(10)
+            }
+            .retry_after(),
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(
+            Error::ServiceUnavailable {
+                retry_after_delay_seconds: None
+            }
+            .retry_after(),
+            None
+        );
+        assert_eq!(Error::BadGateway.retry_after(), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_error_aware_policy_with_retry_after() {
+        use core::ops::ControlFlow;
+
+        use retry_backoff::backoffs::RetryAfter;
+        use retry_predicate::predicates::FnPredicate;
+
+        use crate::{policies::ErrorAwarePolicy, retry_policy::RetryPolicy};
+
+        let policy = ErrorAwarePolicy::new(
+            FnPredicate::from(default_retry_predicate),
+            5,
+            RetryAfter::new(Backoff::default(), Duration::from_secs(60)),
+        );
+
+        for (err, attempts, flow) in [
+            (
+                Error::TooManyRequests {
+                    retry_after_delay_seconds: Some(5),
+                },
+                1,
+                ControlFlow::Continue(Duration::from_secs(5)),
+            ),
+            (
+                Error::ServiceUnavailable {
+                    retry_after_delay_seconds: Some(3600),
+                },
+                1,
+                ControlFlow::Continue(Duration::from_secs(60)),
+            ),
+            (
+                Error::ServiceUnavailable {
+                    retry_after_delay_seconds: None,
+                },
+                1,
+                ControlFlow::Continue(Duration::from_secs(1)),
+            ),
+            (
+                Error::BadGateway,
+                1,
+                ControlFlow::Continue(Duration::from_secs(1)),
+            ),
+        ] {
+            assert_eq!(RetryPolicy::next_step(&policy, &err, attempts), flow);
+        }
+    }
+
     #[cfg(feature = "alloc")]
     #[test]
     fn test_error_display() {
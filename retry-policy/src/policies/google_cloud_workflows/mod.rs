@@ -41,6 +41,15 @@ impl<PParams> crate::retry_policy::RetryPolicy<PParams> for Policy<PParams> {
         &self.backoff
     }
 
+    fn next_step_with_state(
+        &self,
+        params: &PParams,
+        attempts: usize,
+        state: &mut retry_backoff::BackoffState,
+    ) -> core::ops::ControlFlow<crate::retry_policy::StopReason, core::time::Duration> {
+        self.next_step_from_parts(params, attempts, state)
+    }
+
     fn name(&self) -> &str {
         "GoogleCloudWorkflows"
     }
@@ -1,9 +1,13 @@
 //
 #[cfg(feature = "alloc")]
+mod error_aware;
+#[cfg(feature = "alloc")]
 mod r#fn;
 #[cfg(feature = "alloc")]
 mod simple;
 
+#[cfg(feature = "alloc")]
+pub use error_aware::Policy as ErrorAwarePolicy;
 #[cfg(feature = "alloc")]
 pub use r#fn::Policy as FnPolicy;
 #[cfg(feature = "alloc")]
@@ -1,9 +1,10 @@
 use alloc::boxed::Box;
+use core::{ops::ControlFlow, time::Duration};
 
-use retry_backoff::RetryBackoff;
+use retry_backoff::{BackoffState, RetryBackoff};
 use retry_predicate::RetryPredicate;
 
-use crate::retry_policy::RetryPolicy;
+use crate::retry_policy::{RetryPolicy, StopReason};
 
 //
 #[derive(Debug)]
@@ -38,6 +39,19 @@ impl<PParams> RetryPolicy<PParams> for Policy<PParams> {
         self.backoff.as_ref()
     }
 
+    fn next_step_with_state(
+        &self,
+        params: &PParams,
+        attempts: usize,
+        state: &mut BackoffState,
+    ) -> ControlFlow<StopReason, Duration> {
+        self.next_step_from_parts(params, attempts, state)
+    }
+
+    fn attempt_timeout(&self, attempts: usize) -> Option<Duration> {
+        self.backoff.attempt_timeout(attempts)
+    }
+
     fn name(&self) -> &str {
         "Simple"
     }
@@ -47,8 +61,6 @@ impl<PParams> RetryPolicy<PParams> for Policy<PParams> {
 mod tests {
     use super::*;
 
-    use core::{ops::ControlFlow, time::Duration};
-
     use retry_backoff::backoffs::FnBackoff;
     use retry_predicate::predicates::AlwaysPredicate;
 
@@ -65,5 +77,96 @@ mod tests {
             ControlFlow::Continue(Duration::from_secs(1))
         );
         assert_eq!(RetryPolicy::name(&policy), "Simple");
+        assert_eq!(RetryPolicy::attempt_timeout(&policy, 1), None);
+    }
+
+    #[test]
+    fn test_next_step_with_state() {
+        use retry_backoff::{
+            BackoffState, backoffs::DecorrelatedJitterBackoff, random::SeededRandom,
+        };
+
+        let base = Duration::from_millis(100);
+        let policy = Policy::new(
+            AlwaysPredicate,
+            10,
+            DecorrelatedJitterBackoff::new(base, Duration::from_secs(10), SeededRandom::new(1)),
+        );
+
+        let mut state = BackoffState::default();
+        let mut prev_delay = base;
+        for attempts in 1..=10 {
+            match RetryPolicy::next_step_with_state(&policy, &(), attempts, &mut state) {
+                ControlFlow::Continue(dur) => {
+                    assert!(dur >= base && dur <= prev_delay * 3);
+                    assert_eq!(state.prev_delay, Some(dur));
+                    prev_delay = dur;
+                }
+                ControlFlow::Break(reason) => panic!("{reason:?}"),
+            }
+        }
+        assert_eq!(
+            RetryPolicy::next_step_with_state(&policy, &(), 11, &mut state),
+            ControlFlow::Break(crate::retry_policy::StopReason::MaxRetriesReached)
+        );
+    }
+
+    #[test]
+    fn test_next_step_with_backoff_exhausted() {
+        use retry_backoff::backoffs::ExplicitScheduleBackoff;
+
+        use crate::retry_policy::StopReason;
+
+        let policy = Policy::new(
+            AlwaysPredicate,
+            5,
+            ExplicitScheduleBackoff::new([Duration::from_secs(1), Duration::from_secs(5)]),
+        );
+
+        for (attempts, flow) in &[
+            (1, ControlFlow::Continue(Duration::from_secs(1))),
+            (2, ControlFlow::Continue(Duration::from_secs(5))),
+            (3, ControlFlow::Break(StopReason::BackoffExhausted)),
+            (6, ControlFlow::Break(StopReason::MaxRetriesReached)),
+        ] {
+            assert_eq!(RetryPolicy::next_step(&policy, &(), *attempts), *flow);
+        }
+    }
+
+    #[test]
+    fn test_inspect_backoff() {
+        use retry_backoff::{
+            RetryBackoffExt as _,
+            backoffs::{ExponentialBackoff, Jitter},
+            random::SeededRandom,
+        };
+
+        let policy: Policy<()> = Policy::new(
+            AlwaysPredicate,
+            3,
+            Jitter::proportional(
+                ExponentialBackoff::new(Duration::from_secs(1), 2, Duration::from_secs(60)),
+                0.5,
+                SeededRandom::new(1),
+            ),
+        );
+        let backoff = RetryPolicy::backoff(&policy);
+
+        for ((attempts, delay), (bounds_attempts, (min, max))) in backoff
+            .schedule(policy.max_retries)
+            .zip(backoff.schedule_bounds(policy.max_retries))
+        {
+            assert_eq!(attempts, bounds_attempts);
+            assert!(delay >= min && delay <= max);
+        }
+        assert_eq!(backoff.schedule(policy.max_retries).count(), 3);
+        assert_eq!(
+            backoff.min_total_delay(policy.max_retries),
+            Duration::from_millis(3500)
+        );
+        assert_eq!(
+            backoff.max_total_delay(policy.max_retries),
+            Duration::from_millis(10500)
+        );
     }
 }
@@ -1,8 +1,10 @@
 use core::{fmt, ops::ControlFlow, time::Duration};
 
-use retry_backoff::RetryBackoff;
+use retry_backoff::{BackoffState, Description as BackoffDescription, RetryBackoff};
 use retry_predicate::RetryPredicate;
 
+use crate::description::Description;
+
 //
 pub trait RetryPolicy<PParams> {
     fn predicate(&self) -> &dyn RetryPredicate<PParams>;
@@ -10,6 +12,29 @@ pub trait RetryPolicy<PParams> {
     fn backoff(&self) -> &dyn RetryBackoff;
 
     fn next_step(&self, params: &PParams, attempts: usize) -> ControlFlow<StopReason, Duration> {
+        self.next_step_from_parts(params, attempts, &mut BackoffState::default())
+    }
+
+    /// Callers driving a whole retry invocation keep one state across attempts.
+    /// Defaults to `next_step`, dropping the state, so policies overriding only `next_step` keep working.
+    /// Policies with a stateful backoff override this with `next_step_from_parts`.
+    fn next_step_with_state(
+        &self,
+        params: &PParams,
+        attempts: usize,
+        state: &mut BackoffState,
+    ) -> ControlFlow<StopReason, Duration> {
+        let _ = state;
+        self.next_step(params, attempts)
+    }
+
+    /// checks `max_retries` and `predicate`, then records the delay from `delay_for` in state
+    fn next_step_from_parts(
+        &self,
+        params: &PParams,
+        attempts: usize,
+        state: &mut BackoffState,
+    ) -> ControlFlow<StopReason, Duration> {
         if attempts > self.max_retries() {
             return ControlFlow::Break(StopReason::MaxRetriesReached);
         }
@@ -18,7 +43,41 @@ pub trait RetryPolicy<PParams> {
             return ControlFlow::Break(StopReason::PredicateFailed);
         }
 
-        ControlFlow::Continue(self.backoff().delay(attempts))
+        match self.delay_for(params, attempts, state) {
+            Some(delay) => {
+                state.record(delay);
+
+                ControlFlow::Continue(delay)
+            }
+            None => ControlFlow::Break(StopReason::BackoffExhausted),
+        }
+    }
+
+    /// returns None once the backoff is exhausted
+    /// policies with error-aware backoffs override this one
+    fn delay_for(
+        &self,
+        params: &PParams,
+        attempts: usize,
+        state: &BackoffState,
+    ) -> Option<Duration> {
+        let _ = params;
+        self.backoff().try_delay_with_state(attempts, state)
+    }
+
+    /// a timeout for attempts, counted from 1 for the first call, see [`RetryBackoff::attempt_timeout`]
+    /// policies with a backoff override this one to forward it
+    fn attempt_timeout(&self, attempts: usize) -> Option<Duration> {
+        let _ = attempts;
+        None
+    }
+
+    /// reports `max_retries`, `predicate` and `backoff` as `(key, value)` pairs, see [`Description`]
+    /// policies without those, like `FnPolicy`, override this one
+    fn describe(&self, params: &mut dyn FnMut(&str, &dyn fmt::Debug)) {
+        params("max_retries", &self.max_retries());
+        params("predicate", &format_args!("{}", self.predicate().name()));
+        params("backoff", &BackoffDescription::new(self.backoff()));
     }
 
     fn name(&self) -> &str {
@@ -30,13 +89,14 @@ pub trait RetryPolicy<PParams> {
 pub enum StopReason {
     MaxRetriesReached,
     PredicateFailed,
+    BackoffExhausted,
 }
 
 //
 impl<PParams> fmt::Debug for dyn RetryPolicy<PParams> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("RetryPolicy")
-            .field(&RetryPolicy::name(self))
+            .field(&Description::new(self))
             .finish()
     }
 }
@@ -44,7 +104,7 @@ impl<PParams> fmt::Debug for dyn RetryPolicy<PParams> {
 impl<PParams> fmt::Debug for dyn RetryPolicy<PParams> + Send {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("RetryPolicy")
-            .field(&RetryPolicy::name(self))
+            .field(&Description::new(self))
             .finish()
     }
 }
@@ -52,7 +112,7 @@ impl<PParams> fmt::Debug for dyn RetryPolicy<PParams> + Send {
 impl<PParams> fmt::Debug for dyn RetryPolicy<PParams> + Send + Sync {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("RetryPolicy")
-            .field(&RetryPolicy::name(self))
+            .field(&Description::new(self))
             .finish()
     }
 }
@@ -8,5 +8,9 @@ pub mod retry_predicate;
 
 pub use self::retry_predicate::RetryPredicate;
 
+pub mod retry_predicate_ext;
+
+pub use self::retry_predicate_ext::RetryPredicateExt;
+
 //
 pub mod predicates;
@@ -0,0 +1,473 @@
+//! Predicates composed from other predicates, see [`RetryPredicateExt`](crate::RetryPredicateExt).
+//!
+//! With alloc, `name()` is composite, `And(Fn, Not(Never))`; otherwise only the outer name, `And`.
+
+use core::{fmt, marker::PhantomData};
+
+use crate::retry_predicate::RetryPredicate;
+
+#[cfg(feature = "alloc")]
+type Name = alloc::string::String;
+
+#[cfg(not(feature = "alloc"))]
+type Name = &'static str;
+
+// derefs a String, copies out a &'static str
+fn as_str(name: &Name) -> &str {
+    name
+}
+
+//
+/// `name()` is `And(a, b)` with the alloc feature, only `And` without it.
+pub struct And<A, B, Params> {
+    a: A,
+    b: B,
+    name: Name,
+    phantom: PhantomData<fn(&Params) -> bool>,
+}
+
+impl<A, B, Params> And<A, B, Params>
+where
+    A: RetryPredicate<Params>,
+    B: RetryPredicate<Params>,
+{
+    pub fn new(a: A, b: B) -> Self {
+        #[cfg(feature = "alloc")]
+        let name = alloc::format!("And({}, {})", a.name(), b.name());
+        #[cfg(not(feature = "alloc"))]
+        let name = "And";
+
+        Self {
+            a,
+            b,
+            name,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, B, Params> fmt::Debug for And<A, B, Params> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("And").field(&self.name).finish()
+    }
+}
+
+impl<A, B, Params> RetryPredicate<Params> for And<A, B, Params>
+where
+    A: RetryPredicate<Params>,
+    B: RetryPredicate<Params>,
+{
+    fn test(&self, params: &Params) -> bool {
+        self.a.test(params) && self.b.test(params)
+    }
+
+    fn name(&self) -> &str {
+        as_str(&self.name)
+    }
+}
+
+//
+/// `name()` is `Or(a, b)` with the alloc feature, only `Or` without it.
+pub struct Or<A, B, Params> {
+    a: A,
+    b: B,
+    name: Name,
+    phantom: PhantomData<fn(&Params) -> bool>,
+}
+
+impl<A, B, Params> Or<A, B, Params>
+where
+    A: RetryPredicate<Params>,
+    B: RetryPredicate<Params>,
+{
+    pub fn new(a: A, b: B) -> Self {
+        #[cfg(feature = "alloc")]
+        let name = alloc::format!("Or({}, {})", a.name(), b.name());
+        #[cfg(not(feature = "alloc"))]
+        let name = "Or";
+
+        Self {
+            a,
+            b,
+            name,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, B, Params> fmt::Debug for Or<A, B, Params> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Or").field(&self.name).finish()
+    }
+}
+
+impl<A, B, Params> RetryPredicate<Params> for Or<A, B, Params>
+where
+    A: RetryPredicate<Params>,
+    B: RetryPredicate<Params>,
+{
+    fn test(&self, params: &Params) -> bool {
+        self.a.test(params) || self.b.test(params)
+    }
+
+    fn name(&self) -> &str {
+        as_str(&self.name)
+    }
+}
+
+//
+/// `name()` is `Not(inner)` with the alloc feature, only `Not` without it.
+pub struct Not<P, Params> {
+    inner: P,
+    name: Name,
+    phantom: PhantomData<fn(&Params) -> bool>,
+}
+
+impl<P, Params> Not<P, Params>
+where
+    P: RetryPredicate<Params>,
+{
+    pub fn new(inner: P) -> Self {
+        #[cfg(feature = "alloc")]
+        let name = alloc::format!("Not({})", inner.name());
+        #[cfg(not(feature = "alloc"))]
+        let name = "Not";
+
+        Self {
+            inner,
+            name,
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+}
+
+impl<P, Params> fmt::Debug for Not<P, Params> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Not").field(&self.name).finish()
+    }
+}
+
+impl<P, Params> RetryPredicate<Params> for Not<P, Params>
+where
+    P: RetryPredicate<Params>,
+{
+    fn test(&self, params: &Params) -> bool {
+        !self.inner.test(params)
+    }
+
+    fn name(&self) -> &str {
+        as_str(&self.name)
+    }
+}
+
+//
+/// Predicates tested in order, implemented for arrays, slices and `Vec`.
+/// Mixed types work as `&dyn RetryPredicate<Params>` or `Box<dyn RetryPredicate<Params>>`.
+pub trait PredicateList<Params> {
+    /// short-circuiting, false when empty
+    fn any(&self, params: &Params) -> bool;
+
+    /// short-circuiting, true when empty
+    fn all(&self, params: &Params) -> bool;
+
+    fn for_each_name(&self, f: &mut dyn FnMut(&str));
+}
+
+impl<P, Params> PredicateList<Params> for [P]
+where
+    P: RetryPredicate<Params>,
+{
+    fn any(&self, params: &Params) -> bool {
+        self.iter().any(|predicate| predicate.test(params))
+    }
+
+    fn all(&self, params: &Params) -> bool {
+        self.iter().all(|predicate| predicate.test(params))
+    }
+
+    fn for_each_name(&self, f: &mut dyn FnMut(&str)) {
+        self.iter().for_each(|predicate| f(predicate.name()))
+    }
+}
+
+impl<P, Params, const N: usize> PredicateList<Params> for [P; N]
+where
+    P: RetryPredicate<Params>,
+{
+    fn any(&self, params: &Params) -> bool {
+        self.as_slice().any(params)
+    }
+
+    fn all(&self, params: &Params) -> bool {
+        self.as_slice().all(params)
+    }
+
+    fn for_each_name(&self, f: &mut dyn FnMut(&str)) {
+        self.as_slice().for_each_name(f)
+    }
+}
+
+impl<L, Params> PredicateList<Params> for &L
+where
+    L: PredicateList<Params> + ?Sized,
+{
+    fn any(&self, params: &Params) -> bool {
+        (**self).any(params)
+    }
+
+    fn all(&self, params: &Params) -> bool {
+        (**self).all(params)
+    }
+
+    fn for_each_name(&self, f: &mut dyn FnMut(&str)) {
+        (**self).for_each_name(f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P, Params> PredicateList<Params> for alloc::vec::Vec<P>
+where
+    P: RetryPredicate<Params>,
+{
+    fn any(&self, params: &Params) -> bool {
+        self.as_slice().any(params)
+    }
+
+    fn all(&self, params: &Params) -> bool {
+        self.as_slice().all(params)
+    }
+
+    fn for_each_name(&self, f: &mut dyn FnMut(&str)) {
+        self.as_slice().for_each_name(f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn list_name<L, Params>(outer: &str, predicates: &L) -> Name
+where
+    L: PredicateList<Params> + ?Sized,
+{
+    let mut name = alloc::format!("{outer}(");
+    let mut separator = "";
+    predicates.for_each_name(&mut |inner| {
+        name.push_str(separator);
+        name.push_str(inner);
+        separator = ", ";
+    });
+    name.push(')');
+    name
+}
+
+//
+/// `name()` is `AnyOf(a, b, ..)` with the alloc feature, only `AnyOf` without it.
+pub struct AnyOf<L, Params> {
+    predicates: L,
+    name: Name,
+    phantom: PhantomData<fn(&Params) -> bool>,
+}
+
+pub fn any_of<L, Params>(predicates: L) -> AnyOf<L, Params>
+where
+    L: PredicateList<Params>,
+{
+    AnyOf::new(predicates)
+}
+
+impl<L, Params> AnyOf<L, Params>
+where
+    L: PredicateList<Params>,
+{
+    pub fn new(predicates: L) -> Self {
+        #[cfg(feature = "alloc")]
+        let name = list_name("AnyOf", &predicates);
+        #[cfg(not(feature = "alloc"))]
+        let name = "AnyOf";
+
+        Self {
+            predicates,
+            name,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<L, Params> fmt::Debug for AnyOf<L, Params> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AnyOf").field(&self.name).finish()
+    }
+}
+
+impl<L, Params> RetryPredicate<Params> for AnyOf<L, Params>
+where
+    L: PredicateList<Params>,
+{
+    fn test(&self, params: &Params) -> bool {
+        self.predicates.any(params)
+    }
+
+    fn name(&self) -> &str {
+        as_str(&self.name)
+    }
+}
+
+//
+/// `name()` is `AllOf(a, b, ..)` with the alloc feature, only `AllOf` without it.
+pub struct AllOf<L, Params> {
+    predicates: L,
+    name: Name,
+    phantom: PhantomData<fn(&Params) -> bool>,
+}
+
+pub fn all_of<L, Params>(predicates: L) -> AllOf<L, Params>
+where
+    L: PredicateList<Params>,
+{
+    AllOf::new(predicates)
+}
+
+impl<L, Params> AllOf<L, Params>
+where
+    L: PredicateList<Params>,
+{
+    pub fn new(predicates: L) -> Self {
+        #[cfg(feature = "alloc")]
+        let name = list_name("AllOf", &predicates);
+        #[cfg(not(feature = "alloc"))]
+        let name = "AllOf";
+
+        Self {
+            predicates,
+            name,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<L, Params> fmt::Debug for AllOf<L, Params> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AllOf").field(&self.name).finish()
+    }
+}
+
+impl<L, Params> RetryPredicate<Params> for AllOf<L, Params>
+where
+    L: PredicateList<Params>,
+{
+    fn test(&self, params: &Params) -> bool {
+        self.predicates.all(params)
+    }
+
+    fn name(&self) -> &str {
+        as_str(&self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        RetryPredicateExt as _,
+        predicates::{AlwaysPredicate, NeverPredicate},
+    };
+
+    struct Even;
+    impl RetryPredicate<usize> for Even {
+        fn test(&self, params: &usize) -> bool {
+            params.is_multiple_of(2)
+        }
+
+        fn name(&self) -> &str {
+            "Even"
+        }
+    }
+
+    struct Small;
+    impl RetryPredicate<usize> for Small {
+        fn test(&self, params: &usize) -> bool {
+            *params < 10
+        }
+
+        fn name(&self) -> &str {
+            "Small"
+        }
+    }
+
+    #[test]
+    fn test_and_or_not() {
+        let predicate = Even.and(Small);
+        assert!(predicate.test(&2));
+        assert!(!predicate.test(&3));
+        assert!(!predicate.test(&12));
+
+        let predicate = Even.or(Small);
+        assert!(predicate.test(&3));
+        assert!(predicate.test(&12));
+        assert!(!predicate.test(&13));
+
+        let predicate = Even.not();
+        assert!(predicate.test(&3));
+        assert!(!predicate.test(&2));
+
+        let predicate = Even.and(NeverPredicate.not());
+        assert!(predicate.test(&2));
+        assert!(!AlwaysPredicate.and(Even).test(&1));
+    }
+
+    #[test]
+    fn test_any_of_all_of() {
+        let predicate = any_of([Even, Even]);
+        assert!(predicate.test(&2));
+        assert!(!predicate.test(&3));
+
+        let mixed: [&dyn RetryPredicate<usize>; 2] = [&Even, &Small];
+        assert!(any_of(mixed).test(&3));
+        assert!(!all_of(mixed).test(&3));
+        assert!(all_of(mixed).test(&2));
+        assert!(all_of(&mixed[..]).test(&2));
+
+        let empty: [Even; 0] = [];
+        assert!(!any_of(empty).test(&2));
+        let empty: [Even; 0] = [];
+        assert!(all_of(empty).test(&2));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_name() {
+        use alloc::{boxed::Box, format, vec};
+
+        use crate::predicates::FnPredicate;
+
+        let predicate = FnPredicate::from(|params: &usize| *params > 0).and(NeverPredicate.not());
+        assert_eq!(predicate.name(), "And(Fn, Not(Never))");
+        assert_eq!(format!("{predicate:?}"), r#"And("And(Fn, Not(Never))")"#);
+
+        let predicate = any_of(vec![
+            Box::new(Even) as Box<dyn RetryPredicate<usize>>,
+            Box::new(Small.or(Even)),
+        ]);
+        assert_eq!(predicate.name(), "AnyOf(Even, Or(Small, Even))");
+        assert!(predicate.test(&3));
+
+        let empty: [Even; 0] = [];
+        assert_eq!(all_of(empty).name(), "AllOf()");
+
+        let dyn_predicate: &dyn RetryPredicate<usize> = &Even.not();
+        assert_eq!(
+            format!("{dyn_predicate:?}"),
+            r#"RetryPredicate("Not(Even)")"#
+        );
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    #[test]
+    fn test_name() {
+        assert_eq!(Even.and(Small.not()).name(), "And");
+        assert_eq!(any_of([Even]).name(), "AnyOf");
+    }
+}
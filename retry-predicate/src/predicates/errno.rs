@@ -0,0 +1,229 @@
+//! Retries by errno, for FFI and syscalls reporting integers rather than `io::ErrorKind`.
+//!
+//! Codes are the Linux values shared by x86, arm and riscv; syscall style `-errno` is accepted too.
+//! They do not match other targets, e.g. mips or powerpc, so `std::io::Error` is only supported
+//! on Linux and Android on those architectures.
+
+use crate::retry_predicate::RetryPredicate;
+
+//
+pub mod codes {
+    pub const EINTR: i32 = 4;
+    pub const EAGAIN: i32 = 11;
+    pub const EWOULDBLOCK: i32 = EAGAIN;
+    pub const EBUSY: i32 = 16;
+    pub const ENFILE: i32 = 23;
+    pub const EMFILE: i32 = 24;
+    pub const ETXTBSY: i32 = 26;
+    pub const EPIPE: i32 = 32;
+    pub const EDEADLK: i32 = 35;
+    pub const ENOLCK: i32 = 37;
+    pub const ENETDOWN: i32 = 100;
+    pub const ENETUNREACH: i32 = 101;
+    pub const ENETRESET: i32 = 102;
+    pub const ECONNABORTED: i32 = 103;
+    pub const ECONNRESET: i32 = 104;
+    pub const ENOBUFS: i32 = 105;
+    pub const ENOTCONN: i32 = 107;
+    pub const ETIMEDOUT: i32 = 110;
+    pub const ECONNREFUSED: i32 = 111;
+    pub const EHOSTDOWN: i32 = 112;
+    pub const EHOSTUNREACH: i32 = 113;
+}
+
+use codes::*;
+
+pub const NETWORK_TRANSIENT: &[i32] = &[
+    EINTR,
+    EAGAIN,
+    EPIPE,
+    ENETDOWN,
+    ENETUNREACH,
+    ENETRESET,
+    ECONNABORTED,
+    ECONNRESET,
+    ENOBUFS,
+    ENOTCONN,
+    ETIMEDOUT,
+    ECONNREFUSED,
+    EHOSTDOWN,
+    EHOSTUNREACH,
+];
+
+pub const FILESYSTEM_CONTENTION: &[i32] = &[
+    EINTR, EAGAIN, EBUSY, ENFILE, EMFILE, ETXTBSY, EDEADLK, ENOLCK,
+];
+
+//
+/// Params carrying an errno.
+pub trait RawOsError {
+    fn raw_os_error(&self) -> Option<i32>;
+}
+
+/// `errno` or `-errno`
+impl RawOsError for i32 {
+    fn raw_os_error(&self) -> Option<i32> {
+        if *self < 0 {
+            self.checked_neg()
+        } else {
+            Some(*self)
+        }
+    }
+}
+
+/// Only on Linux and Android on x86, arm and riscv, other targets number errno differently than [`codes`].
+#[cfg(all(
+    feature = "std",
+    any(target_os = "linux", target_os = "android"),
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "riscv32",
+        target_arch = "riscv64"
+    )
+))]
+impl RawOsError for std::io::Error {
+    fn raw_os_error(&self) -> Option<i32> {
+        std::io::Error::raw_os_error(self)
+    }
+}
+
+impl<T> RawOsError for &T
+where
+    T: RawOsError + ?Sized,
+{
+    fn raw_os_error(&self) -> Option<i32> {
+        (**self).raw_os_error()
+    }
+}
+
+//
+/// `S` is `&'static [i32]`, `[i32; N]`, `Vec<i32>` and so on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Predicate<S = &'static [i32]> {
+    codes: S,
+}
+
+impl<S> Predicate<S> {
+    pub const fn new(codes: S) -> Self {
+        Self { codes }
+    }
+}
+
+impl Predicate {
+    /// [`NETWORK_TRANSIENT`]
+    pub const fn network_transient() -> Self {
+        Self::new(NETWORK_TRANSIENT)
+    }
+
+    /// [`FILESYSTEM_CONTENTION`]
+    pub const fn filesystem_contention() -> Self {
+        Self::new(FILESYSTEM_CONTENTION)
+    }
+}
+
+impl<S> Predicate<S>
+where
+    S: AsRef<[i32]>,
+{
+    pub fn codes(&self) -> &[i32] {
+        self.codes.as_ref()
+    }
+}
+
+//
+impl<S, Params> RetryPredicate<Params> for Predicate<S>
+where
+    S: AsRef<[i32]>,
+    Params: RawOsError,
+{
+    fn test(&self, params: &Params) -> bool {
+        params
+            .raw_os_error()
+            .is_some_and(|code| self.codes().contains(&code))
+    }
+
+    fn name(&self) -> &str {
+        "Errno"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_network_transient() {
+        let predicate = Predicate::network_transient();
+
+        for code in [EAGAIN, EINTR, ECONNREFUSED, ECONNRESET, ENOBUFS, ETIMEDOUT] {
+            assert!(RetryPredicate::test(&predicate, &code), "{code}");
+            assert!(RetryPredicate::test(&predicate, &-code), "{code}");
+        }
+        for code in [0, 1, 2, EBUSY, ETXTBSY, i32::MIN, i32::MAX] {
+            assert!(!RetryPredicate::test(&predicate, &code), "{code}");
+        }
+
+        assert_eq!(RetryPredicate::<i32>::name(&predicate), "Errno");
+    }
+
+    #[test]
+    fn test_filesystem_contention() {
+        let predicate = Predicate::filesystem_contention();
+
+        for code in [EAGAIN, EINTR, EBUSY, ETXTBSY, EDEADLK, ENOLCK] {
+            assert!(RetryPredicate::test(&predicate, &code), "{code}");
+        }
+        for code in [ECONNREFUSED, ECONNRESET] {
+            assert!(!RetryPredicate::test(&predicate, &code), "{code}");
+        }
+    }
+
+    #[test]
+    fn test_custom_codes() {
+        let predicate = Predicate::new([EBUSY]);
+        assert!(RetryPredicate::test(&predicate, &EBUSY));
+        assert!(!RetryPredicate::test(&predicate, &EAGAIN));
+        assert_eq!(predicate.codes(), &[EBUSY]);
+
+        struct SysError(i32);
+        impl RawOsError for SysError {
+            fn raw_os_error(&self) -> Option<i32> {
+                Some(self.0)
+            }
+        }
+        assert!(RetryPredicate::test(&predicate, &SysError(EBUSY)));
+    }
+
+    #[cfg(all(
+        feature = "std",
+        any(target_os = "linux", target_os = "android"),
+        any(
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "arm",
+            target_arch = "aarch64",
+            target_arch = "riscv32",
+            target_arch = "riscv64"
+        )
+    ))]
+    #[test]
+    fn test_io_error() {
+        let predicate = Predicate::network_transient();
+
+        assert!(RetryPredicate::test(
+            &predicate,
+            &std::io::Error::from_raw_os_error(ECONNRESET)
+        ));
+        assert_eq!(
+            std::io::Error::from_raw_os_error(ECONNREFUSED).kind(),
+            std::io::ErrorKind::ConnectionRefused
+        );
+        assert!(!RetryPredicate::test(
+            &predicate,
+            &std::io::Error::from(std::io::ErrorKind::ConnectionReset)
+        ));
+    }
+}
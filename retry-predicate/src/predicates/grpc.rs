@@ -0,0 +1,217 @@
+//! Retries by gRPC status code, see [gRPC Retry Design](https://github.com/grpc/proposal/blob/master/A6-client-retries.md).
+//!
+//! A server may push back with `grpc-retry-pushback-ms`: a negative or malformed value stops the retries,
+//! otherwise the value is the delay, read it with [`retry_pushback`], e.g. from `RetryAfterFn` in retry-backoff.
+
+use core::{fmt, time::Duration};
+
+use tonic::{Code, Status};
+
+use crate::retry_predicate::RetryPredicate;
+
+pub const RETRY_PUSHBACK_KEY: &str = "grpc-retry-pushback-ms";
+
+//
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pushback {
+    RetryAfter(Duration),
+    DoNotRetry,
+}
+
+impl Pushback {
+    /// None when the metadata is absent
+    pub fn from_status(status: &Status) -> Option<Self> {
+        let value = status.metadata().get(RETRY_PUSHBACK_KEY)?;
+        match value.to_str().ok().and_then(|s| s.parse::<i64>().ok()) {
+            Some(ms) if ms >= 0 => Some(Self::RetryAfter(Duration::from_millis(ms as u64))),
+            _ => Some(Self::DoNotRetry),
+        }
+    }
+}
+
+/// the pushback delay, None when absent or when the server says not to retry
+pub fn retry_pushback(status: &Status) -> Option<Duration> {
+    match Pushback::from_status(status)? {
+        Pushback::RetryAfter(delay) => Some(delay),
+        Pushback::DoNotRetry => None,
+    }
+}
+
+//
+/// A set of codes, built in const contexts.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Predicate {
+    bits: u32,
+    /// stop on a `DoNotRetry` pushback, even for a retryable code
+    pub honor_pushback: bool,
+}
+
+impl fmt::Debug for Predicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Predicate")
+            .field(
+                "codes",
+                &fmt::from_fn(|f| {
+                    f.debug_set()
+                        .entries(
+                            (0..32)
+                                .filter(|i| self.bits & (1 << i) != 0)
+                                .map(Code::from_i32),
+                        )
+                        .finish()
+                }),
+            )
+            .field("honor_pushback", &self.honor_pushback)
+            .finish()
+    }
+}
+
+impl Default for Predicate {
+    fn default() -> Self {
+        Self::default_retryable()
+    }
+}
+
+impl Predicate {
+    /// matches nothing
+    pub const fn empty() -> Self {
+        Self {
+            bits: 0,
+            honor_pushback: true,
+        }
+    }
+
+    /// Unavailable, ResourceExhausted and Aborted
+    /// add DeadlineExceeded when the deadline is per attempt
+    pub const fn default_retryable() -> Self {
+        Self::empty()
+            .with_code(Code::Unavailable)
+            .with_code(Code::ResourceExhausted)
+            .with_code(Code::Aborted)
+    }
+
+    pub const fn with_code(mut self, code: Code) -> Self {
+        self.bits |= 1 << (code as i32);
+        self
+    }
+
+    pub const fn without_code(mut self, code: Code) -> Self {
+        self.bits &= !(1 << (code as i32));
+        self
+    }
+
+    pub const fn ignore_pushback(mut self) -> Self {
+        self.honor_pushback = false;
+        self
+    }
+
+    pub const fn contains(&self, code: Code) -> bool {
+        self.bits & (1 << (code as i32)) != 0
+    }
+}
+
+//
+impl RetryPredicate<Code> for Predicate {
+    fn test(&self, params: &Code) -> bool {
+        self.contains(*params)
+    }
+
+    fn name(&self) -> &str {
+        "GrpcStatus"
+    }
+}
+
+impl RetryPredicate<Status> for Predicate {
+    fn test(&self, params: &Status) -> bool {
+        self.contains(params.code())
+            && !(self.honor_pushback && Pushback::from_status(params) == Some(Pushback::DoNotRetry))
+    }
+
+    fn name(&self) -> &str {
+        "GrpcStatus"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tonic::metadata::MetadataMap;
+
+    fn status_with_pushback(code: Code, pushback: &'static str) -> Status {
+        let mut metadata = MetadataMap::new();
+        metadata.insert(RETRY_PUSHBACK_KEY, pushback.parse().unwrap());
+        Status::with_metadata(code, "", metadata)
+    }
+
+    #[test]
+    fn test_default_retryable() {
+        const PREDICATE: Predicate = Predicate::default_retryable();
+
+        for code in [Code::Unavailable, Code::ResourceExhausted, Code::Aborted] {
+            assert!(RetryPredicate::test(&PREDICATE, &code), "{code:?}");
+            assert!(RetryPredicate::test(&PREDICATE, &Status::new(code, "")));
+        }
+        for code in [
+            Code::Ok,
+            Code::Cancelled,
+            Code::DeadlineExceeded,
+            Code::InvalidArgument,
+            Code::Internal,
+            Code::Unauthenticated,
+        ] {
+            assert!(!RetryPredicate::test(&PREDICATE, &code), "{code:?}");
+        }
+        assert_eq!(RetryPredicate::<Code>::name(&PREDICATE), "GrpcStatus");
+
+        let predicate = PREDICATE
+            .with_code(Code::DeadlineExceeded)
+            .without_code(Code::Aborted);
+        assert!(predicate.contains(Code::DeadlineExceeded));
+        assert!(!predicate.contains(Code::Aborted));
+
+        assert_eq!(
+            format!("{PREDICATE:?}"),
+            "Predicate { codes: {ResourceExhausted, Aborted, Unavailable}, honor_pushback: true }"
+        );
+    }
+
+    #[test]
+    fn test_pushback() {
+        let predicate = Predicate::default();
+
+        for (pushback, expected) in [
+            (
+                "250",
+                Some(Pushback::RetryAfter(Duration::from_millis(250))),
+            ),
+            ("0", Some(Pushback::RetryAfter(Duration::ZERO))),
+            ("-1", Some(Pushback::DoNotRetry)),
+            ("soon", Some(Pushback::DoNotRetry)),
+        ] {
+            let status = status_with_pushback(Code::Unavailable, pushback);
+            assert_eq!(Pushback::from_status(&status), expected, "{pushback}");
+            assert_eq!(
+                RetryPredicate::test(&predicate, &status),
+                expected != Some(Pushback::DoNotRetry)
+            );
+            assert!(RetryPredicate::test(&predicate.ignore_pushback(), &status));
+        }
+
+        let status = Status::unavailable("");
+        assert_eq!(Pushback::from_status(&status), None);
+        assert_eq!(retry_pushback(&status), None);
+        assert_eq!(
+            retry_pushback(&status_with_pushback(Code::Unavailable, "1500")),
+            Some(Duration::from_millis(1500))
+        );
+        assert_eq!(
+            retry_pushback(&status_with_pushback(Code::Unavailable, "-1")),
+            None
+        );
+
+        // a pushback does not make a code retryable
+        let status = status_with_pushback(Code::Internal, "100");
+        assert!(!RetryPredicate::test(&predicate, &status));
+    }
+}
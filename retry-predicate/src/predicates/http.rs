@@ -0,0 +1,270 @@
+//! Retries by HTTP status, optionally only when the request method is idempotent.
+
+use core::{fmt, ops::RangeInclusive};
+
+use http::{Method, Response, StatusCode};
+
+use crate::retry_predicate::RetryPredicate;
+
+// One bit per code in 0..1024, covering every valid StatusCode (100..=999).
+const WORDS: usize = 16;
+
+//
+/// A set of statuses, built in const contexts.
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub struct Predicate {
+    bits: [u64; WORDS],
+}
+
+impl fmt::Debug for Predicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries((100..=999).filter(|code| self.contains_u16(*code)))
+            .finish()
+    }
+}
+
+impl Predicate {
+    /// matches nothing
+    pub const fn empty() -> Self {
+        Self { bits: [0; WORDS] }
+    }
+
+    /// [Function: http.default_retry_predicate](https://cloud.google.com/workflows/docs/reference/stdlib/http/default_retry_predicate)
+    /// 429, 502, 503 and 504; connection errors and timeouts have no status
+    pub const fn default_retry() -> Self {
+        Self::empty()
+            .with_status(StatusCode::TOO_MANY_REQUESTS)
+            .with_status(StatusCode::BAD_GATEWAY)
+            .with_status(StatusCode::SERVICE_UNAVAILABLE)
+            .with_status(StatusCode::GATEWAY_TIMEOUT)
+    }
+
+    /// [Function: http.default_retry_predicate_non_idempotent](https://cloud.google.com/workflows/docs/reference/stdlib/http/default_retry_predicate_non_idempotent)
+    /// 429 and 503, the request was not processed
+    pub const fn default_retry_non_idempotent() -> Self {
+        Self::empty()
+            .with_status(StatusCode::TOO_MANY_REQUESTS)
+            .with_status(StatusCode::SERVICE_UNAVAILABLE)
+    }
+
+    pub const fn with_status(self, status: StatusCode) -> Self {
+        self.with_range(status.as_u16()..=status.as_u16())
+    }
+
+    pub const fn without_status(self, status: StatusCode) -> Self {
+        self.without_range(status.as_u16()..=status.as_u16())
+    }
+
+    /// e.g. `500..=599` for 5xx, codes outside 100..=999 are ignored
+    pub const fn with_range(mut self, range: RangeInclusive<u16>) -> Self {
+        let mut code = *range.start();
+        while code <= *range.end() && code < 1000 {
+            if code >= 100 {
+                self.bits[code as usize / 64] |= 1 << (code % 64);
+            }
+            code += 1;
+        }
+        self
+    }
+
+    pub const fn without_range(mut self, range: RangeInclusive<u16>) -> Self {
+        let mut code = *range.start();
+        while code <= *range.end() && code < 1000 {
+            self.bits[code as usize / 64] &= !(1 << (code % 64));
+            code += 1;
+        }
+        self
+    }
+
+    pub const fn contains(&self, status: StatusCode) -> bool {
+        self.contains_u16(status.as_u16())
+    }
+
+    const fn contains_u16(&self, code: u16) -> bool {
+        code < 1000 && self.bits[code as usize / 64] & (1 << (code % 64)) != 0
+    }
+}
+
+//
+impl RetryPredicate<StatusCode> for Predicate {
+    fn test(&self, params: &StatusCode) -> bool {
+        self.contains(*params)
+    }
+
+    fn name(&self) -> &str {
+        "HttpStatus"
+    }
+}
+
+impl<B> RetryPredicate<Response<B>> for Predicate {
+    fn test(&self, params: &Response<B>) -> bool {
+        self.contains(params.status())
+    }
+
+    fn name(&self) -> &str {
+        "HttpStatus"
+    }
+}
+
+//
+/// Statuses per request method, for params paired with the [`Method`].
+/// Idempotent methods are the RFC 9110 ones, see [`Method::is_idempotent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MethodAware {
+    pub idempotent: Predicate,
+    pub non_idempotent: Predicate,
+}
+
+impl Default for MethodAware {
+    /// `default_retry` for idempotent methods, `default_retry_non_idempotent` otherwise
+    fn default() -> Self {
+        Self::new(
+            Predicate::default_retry(),
+            Predicate::default_retry_non_idempotent(),
+        )
+    }
+}
+
+impl MethodAware {
+    pub const fn new(idempotent: Predicate, non_idempotent: Predicate) -> Self {
+        Self {
+            idempotent,
+            non_idempotent,
+        }
+    }
+
+    pub fn statuses_for(&self, method: &Method) -> &Predicate {
+        if method.is_idempotent() {
+            &self.idempotent
+        } else {
+            &self.non_idempotent
+        }
+    }
+}
+
+impl RetryPredicate<(Method, StatusCode)> for MethodAware {
+    fn test(&self, (method, status): &(Method, StatusCode)) -> bool {
+        self.statuses_for(method).contains(*status)
+    }
+
+    fn name(&self) -> &str {
+        "HttpMethodAware"
+    }
+}
+
+impl<B> RetryPredicate<(Method, Response<B>)> for MethodAware {
+    fn test(&self, (method, response): &(Method, Response<B>)) -> bool {
+        self.statuses_for(method).contains(response.status())
+    }
+
+    fn name(&self) -> &str {
+        "HttpMethodAware"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_retry() {
+        const PREDICATE: Predicate = Predicate::default_retry();
+
+        for status in [
+            StatusCode::TOO_MANY_REQUESTS,
+            StatusCode::BAD_GATEWAY,
+            StatusCode::SERVICE_UNAVAILABLE,
+            StatusCode::GATEWAY_TIMEOUT,
+        ] {
+            assert!(RetryPredicate::test(&PREDICATE, &status), "{status}");
+        }
+        for status in [
+            StatusCode::OK,
+            StatusCode::NOT_FOUND,
+            StatusCode::REQUEST_TIMEOUT,
+            StatusCode::INTERNAL_SERVER_ERROR,
+        ] {
+            assert!(!RetryPredicate::test(&PREDICATE, &status), "{status}");
+        }
+        assert_eq!(RetryPredicate::<StatusCode>::name(&PREDICATE), "HttpStatus");
+
+        let predicate = Predicate::default_retry_non_idempotent();
+        assert!(predicate.contains(StatusCode::TOO_MANY_REQUESTS));
+        assert!(predicate.contains(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!predicate.contains(StatusCode::BAD_GATEWAY));
+        assert!(!predicate.contains(StatusCode::GATEWAY_TIMEOUT));
+    }
+
+    #[test]
+    fn test_ranges() {
+        let predicate = Predicate::empty()
+            .with_range(500..=599)
+            .with_status(StatusCode::TOO_MANY_REQUESTS)
+            .with_status(StatusCode::REQUEST_TIMEOUT)
+            .without_status(StatusCode::NOT_IMPLEMENTED);
+
+        for code in [408, 429, 500, 502, 599] {
+            let status = StatusCode::from_u16(code).unwrap();
+            assert!(predicate.contains(status), "{code}");
+        }
+        for code in [100, 200, 404, 499, 501, 600, 999] {
+            let status = StatusCode::from_u16(code).unwrap();
+            assert!(!predicate.contains(status), "{code}");
+        }
+
+        let predicate = Predicate::empty().with_range(0..=u16::MAX);
+        assert!(predicate.contains(StatusCode::CONTINUE));
+        assert!(predicate.contains(StatusCode::from_u16(999).unwrap()));
+        assert_eq!(predicate.without_range(0..=u16::MAX), Predicate::empty());
+
+        assert_eq!(
+            format!("{:?}", Predicate::default_retry_non_idempotent()),
+            "{429, 503}"
+        );
+    }
+
+    #[test]
+    fn test_response() {
+        let predicate = Predicate::default_retry();
+
+        let response = Response::builder().status(503).body(()).unwrap();
+        assert!(RetryPredicate::test(&predicate, &response));
+        let response = Response::builder().status(200).body(()).unwrap();
+        assert!(!RetryPredicate::test(&predicate, &response));
+    }
+
+    #[test]
+    fn test_method_aware() {
+        let predicate = MethodAware::default();
+
+        for method in [Method::GET, Method::PUT, Method::DELETE, Method::HEAD] {
+            assert!(RetryPredicate::test(
+                &predicate,
+                &(method.clone(), StatusCode::BAD_GATEWAY)
+            ));
+            assert!(RetryPredicate::test(
+                &predicate,
+                &(method, StatusCode::TOO_MANY_REQUESTS)
+            ));
+        }
+        for method in [Method::POST, Method::PATCH] {
+            assert!(!RetryPredicate::test(
+                &predicate,
+                &(method.clone(), StatusCode::BAD_GATEWAY)
+            ));
+            assert!(RetryPredicate::test(
+                &predicate,
+                &(method, StatusCode::SERVICE_UNAVAILABLE)
+            ));
+        }
+
+        let response = Response::builder().status(504).body(()).unwrap();
+        assert!(!RetryPredicate::test(&predicate, &(Method::POST, response)));
+
+        assert_eq!(
+            RetryPredicate::<(Method, StatusCode)>::name(&predicate),
+            "HttpMethodAware"
+        );
+    }
+}
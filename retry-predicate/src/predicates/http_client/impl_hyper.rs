@@ -0,0 +1,119 @@
+use hyper_util::client::legacy::Error as LegacyClientError;
+
+use super::{ErrorClass, Predicate};
+use crate::retry_predicate::RetryPredicate;
+
+/// None when hyper says nothing more specific, e.g. for an io error it wraps
+pub(super) fn classify_hyper(err: &hyper::Error) -> Option<ErrorClass> {
+    if err.is_timeout() {
+        Some(ErrorClass::Timeout)
+    } else if err.is_parse() || err.is_parse_status() {
+        Some(ErrorClass::Protocol)
+    } else if err.is_incomplete_message()
+        || err.is_closed()
+        || err.is_canceled()
+        || err.is_body_write_aborted()
+    {
+        Some(ErrorClass::Connection)
+    } else if err.is_user() {
+        Some(ErrorClass::Other)
+    } else {
+        None
+    }
+}
+
+pub fn classify(err: &hyper::Error) -> ErrorClass {
+    ErrorClass::from_source_chain(err).unwrap_or(ErrorClass::Other)
+}
+
+/// for `hyper_util::client::legacy::Client`
+pub fn classify_legacy_client(err: &LegacyClientError) -> ErrorClass {
+    if err.is_connect() {
+        return ErrorClass::Connect;
+    }
+    std::error::Error::source(err)
+        .and_then(ErrorClass::from_source_chain)
+        .unwrap_or(ErrorClass::Other)
+}
+
+//
+impl RetryPredicate<hyper::Error> for Predicate {
+    fn test(&self, params: &hyper::Error) -> bool {
+        self.test_class(classify(params))
+    }
+
+    fn name(&self) -> &str {
+        "HttpClient"
+    }
+}
+
+impl RetryPredicate<LegacyClientError> for Predicate {
+    fn test(&self, params: &LegacyClientError) -> bool {
+        self.test_class(classify_legacy_client(params))
+    }
+
+    fn name(&self) -> &str {
+        "HttpClient"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::net::SocketAddr;
+
+    use http::{StatusCode, Uri};
+    use http_body_util::{BodyExt as _, Empty};
+    use hyper::body::Bytes;
+    use hyper_util::{
+        client::legacy::{Client, ResponseFuture},
+        rt::TokioExecutor,
+    };
+
+    use crate::predicates::http_client::loopback::*;
+
+    fn get(addr: SocketAddr) -> ResponseFuture {
+        let client = Client::builder(TokioExecutor::new()).build_http::<Empty<Bytes>>();
+        client.get(Uri::try_from(format!("http://{addr}/")).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_connect() {
+        let err = get(refused_addr().await).await.unwrap_err();
+        assert_eq!(classify_legacy_client(&err), ErrorClass::Connect);
+        assert!(RetryPredicate::test(&Predicate::non_idempotent(), &err));
+    }
+
+    #[tokio::test]
+    async fn test_protocol() {
+        let err = get(serve_once(Some(NOT_HTTP)).await).await.unwrap_err();
+        assert_eq!(classify_legacy_client(&err), ErrorClass::Protocol);
+        assert!(!RetryPredicate::test(&Predicate::default(), &err));
+    }
+
+    #[tokio::test]
+    async fn test_connection() {
+        let err = get(serve_once(Some(b"")).await).await.unwrap_err();
+        assert_eq!(classify_legacy_client(&err), ErrorClass::Connection);
+        assert!(RetryPredicate::test(&Predicate::default(), &err));
+        assert!(!RetryPredicate::test(&Predicate::non_idempotent(), &err));
+
+        let response = get(serve_once(Some(TRUNCATED_BODY)).await).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let err = response.into_body().collect().await.unwrap_err();
+        assert_eq!(classify(&err), ErrorClass::Connection);
+        assert!(RetryPredicate::test(&Predicate::default(), &err));
+    }
+
+    #[tokio::test]
+    async fn test_status() {
+        let response = get(serve_once(Some(SERVICE_UNAVAILABLE)).await)
+            .await
+            .unwrap();
+        assert!(RetryPredicate::test(&Predicate::default(), &response));
+
+        let response = get(serve_once(Some(NOT_FOUND)).await).await.unwrap();
+        assert!(!RetryPredicate::test(&Predicate::default(), &response));
+    }
+}
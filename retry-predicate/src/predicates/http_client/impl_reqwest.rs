@@ -0,0 +1,99 @@
+use super::{ErrorClass, Predicate};
+use crate::retry_predicate::RetryPredicate;
+
+pub fn classify(err: &reqwest::Error) -> ErrorClass {
+    if err.is_connect() {
+        return ErrorClass::Connect;
+    }
+    if err.is_timeout() {
+        return ErrorClass::Timeout;
+    }
+    if let Some(status) = err.status() {
+        return ErrorClass::Status(status);
+    }
+    std::error::Error::source(err)
+        .and_then(ErrorClass::from_source_chain)
+        .unwrap_or(ErrorClass::Other)
+}
+
+//
+impl RetryPredicate<reqwest::Error> for Predicate {
+    fn test(&self, params: &reqwest::Error) -> bool {
+        self.test_class(classify(params))
+    }
+
+    fn name(&self) -> &str {
+        "HttpClient"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{net::SocketAddr, time::Duration};
+
+    use http::StatusCode;
+
+    use crate::predicates::http_client::loopback::*;
+
+    async fn get(addr: SocketAddr) -> Result<reqwest::Response, reqwest::Error> {
+        reqwest::Client::builder()
+            .timeout(Duration::from_millis(200))
+            .build()
+            .unwrap()
+            .get(format!("http://{addr}/"))
+            .send()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_connect() {
+        let err = get(refused_addr().await).await.unwrap_err();
+        assert_eq!(classify(&err), ErrorClass::Connect);
+        assert!(RetryPredicate::test(&Predicate::non_idempotent(), &err));
+    }
+
+    #[tokio::test]
+    async fn test_timeout() {
+        let err = get(serve_once(None).await).await.unwrap_err();
+        assert_eq!(classify(&err), ErrorClass::Timeout);
+        assert!(RetryPredicate::test(&Predicate::default(), &err));
+        assert!(!RetryPredicate::test(&Predicate::non_idempotent(), &err));
+    }
+
+    #[tokio::test]
+    async fn test_protocol() {
+        let err = get(serve_once(Some(NOT_HTTP)).await).await.unwrap_err();
+        assert_eq!(classify(&err), ErrorClass::Protocol);
+        assert!(!RetryPredicate::test(&Predicate::default(), &err));
+    }
+
+    #[tokio::test]
+    async fn test_connection() {
+        let err = get(serve_once(Some(b"")).await).await.unwrap_err();
+        assert_eq!(classify(&err), ErrorClass::Connection);
+
+        let response = get(serve_once(Some(TRUNCATED_BODY)).await).await.unwrap();
+        let err = response.bytes().await.unwrap_err();
+        assert_eq!(classify(&err), ErrorClass::Connection);
+        assert!(RetryPredicate::test(&Predicate::default(), &err));
+    }
+
+    #[tokio::test]
+    async fn test_status() {
+        let response = get(serve_once(Some(SERVICE_UNAVAILABLE)).await)
+            .await
+            .unwrap();
+        let err = response.error_for_status().unwrap_err();
+        assert_eq!(
+            classify(&err),
+            ErrorClass::Status(StatusCode::SERVICE_UNAVAILABLE)
+        );
+        assert!(RetryPredicate::test(&Predicate::default(), &err));
+
+        let response = get(serve_once(Some(NOT_FOUND)).await).await.unwrap();
+        let err = response.error_for_status().unwrap_err();
+        assert!(!RetryPredicate::test(&Predicate::default(), &err));
+    }
+}
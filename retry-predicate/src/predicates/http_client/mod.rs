@@ -0,0 +1,246 @@
+//! Retries HTTP client errors by [`ErrorClass`], for `hyper`/`hyper-util` and `reqwest`.
+//!
+//! Connecting failed before the request was sent, so it is safe for any method.
+//! Timeouts and connection failures after that may have been processed.
+
+use std::{error::Error as StdError, io};
+
+use http::{Response, StatusCode};
+
+use super::http::Predicate as HttpStatusPredicate;
+use crate::retry_predicate::RetryPredicate;
+
+#[cfg(feature = "hyper")]
+pub mod impl_hyper;
+
+#[cfg(feature = "reqwest")]
+pub mod impl_reqwest;
+
+//
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorClass {
+    /// the connection was not established, the request was not sent
+    Connect,
+    /// timed out, the request may have been processed
+    Timeout,
+    /// the connection failed once established, e.g. closed or reset before the whole response was read
+    Connection,
+    /// the peer sent malformed HTTP
+    Protocol,
+    /// a response with this status
+    Status(StatusCode),
+    /// building the request, redirects, decoding and so on
+    Other,
+}
+
+impl ErrorClass {
+    /// classifies by the first hyper or io error in the chain, starting from err itself
+    pub fn from_source_chain(err: &(dyn StdError + 'static)) -> Option<Self> {
+        let mut source = Some(err);
+        while let Some(err) = source {
+            #[cfg(feature = "hyper")]
+            if let Some(err) = err.downcast_ref::<hyper::Error>()
+                && let Some(class) = impl_hyper::classify_hyper(err)
+            {
+                return Some(class);
+            }
+            if let Some(err) = err.downcast_ref::<io::Error>() {
+                return Self::from_io_error_kind(err.kind());
+            }
+            source = err.source();
+        }
+        None
+    }
+
+    pub fn from_io_error_kind(kind: io::ErrorKind) -> Option<Self> {
+        use io::ErrorKind::*;
+
+        match kind {
+            TimedOut => Some(Self::Timeout),
+            ConnectionRefused | HostUnreachable | NetworkUnreachable | NetworkDown
+            | AddrNotAvailable => Some(Self::Connect),
+            ConnectionReset | ConnectionAborted | NotConnected | BrokenPipe | UnexpectedEof => {
+                Some(Self::Connection)
+            }
+            _ => None,
+        }
+    }
+}
+
+//
+/// Which [`ErrorClass`]es to retry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Predicate {
+    pub connect: bool,
+    pub timeout: bool,
+    pub connection: bool,
+    pub protocol: bool,
+    pub statuses: HttpStatusPredicate,
+}
+
+impl Default for Predicate {
+    fn default() -> Self {
+        Self::idempotent()
+    }
+}
+
+impl Predicate {
+    /// everything but protocol errors, statuses from `HttpStatusPredicate::default_retry`
+    pub const fn idempotent() -> Self {
+        Self {
+            connect: true,
+            timeout: true,
+            connection: true,
+            protocol: false,
+            statuses: HttpStatusPredicate::default_retry(),
+        }
+    }
+
+    /// only what was not processed, statuses from `HttpStatusPredicate::default_retry_non_idempotent`
+    pub const fn non_idempotent() -> Self {
+        Self {
+            connect: true,
+            timeout: false,
+            connection: false,
+            protocol: false,
+            statuses: HttpStatusPredicate::default_retry_non_idempotent(),
+        }
+    }
+
+    pub const fn test_class(&self, class: ErrorClass) -> bool {
+        match class {
+            ErrorClass::Connect => self.connect,
+            ErrorClass::Timeout => self.timeout,
+            ErrorClass::Connection => self.connection,
+            ErrorClass::Protocol => self.protocol,
+            ErrorClass::Status(status) => self.statuses.contains(status),
+            ErrorClass::Other => false,
+        }
+    }
+}
+
+//
+impl RetryPredicate<ErrorClass> for Predicate {
+    fn test(&self, params: &ErrorClass) -> bool {
+        self.test_class(*params)
+    }
+
+    fn name(&self) -> &str {
+        "HttpClient"
+    }
+}
+
+impl<B> RetryPredicate<Response<B>> for Predicate {
+    fn test(&self, params: &Response<B>) -> bool {
+        self.statuses.contains(params.status())
+    }
+
+    fn name(&self) -> &str {
+        "HttpClient"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_test_class() {
+        let predicate = Predicate::default();
+        for class in [
+            ErrorClass::Connect,
+            ErrorClass::Timeout,
+            ErrorClass::Connection,
+            ErrorClass::Status(StatusCode::SERVICE_UNAVAILABLE),
+        ] {
+            assert!(RetryPredicate::test(&predicate, &class), "{class:?}");
+        }
+        for class in [
+            ErrorClass::Protocol,
+            ErrorClass::Status(StatusCode::NOT_FOUND),
+            ErrorClass::Other,
+        ] {
+            assert!(!RetryPredicate::test(&predicate, &class), "{class:?}");
+        }
+
+        let predicate = Predicate::non_idempotent();
+        assert!(predicate.test_class(ErrorClass::Connect));
+        assert!(!predicate.test_class(ErrorClass::Timeout));
+        assert!(!predicate.test_class(ErrorClass::Connection));
+        assert!(!predicate.test_class(ErrorClass::Status(StatusCode::BAD_GATEWAY)));
+
+        assert_eq!(RetryPredicate::<ErrorClass>::name(&predicate), "HttpClient");
+    }
+
+    #[test]
+    fn test_from_source_chain() {
+        let err = io::Error::other(io::Error::from(io::ErrorKind::ConnectionReset));
+        assert_eq!(ErrorClass::from_source_chain(&err), None);
+
+        let err = io::Error::from(io::ErrorKind::ConnectionRefused);
+        assert_eq!(
+            ErrorClass::from_source_chain(&err),
+            Some(ErrorClass::Connect)
+        );
+        assert_eq!(
+            ErrorClass::from_io_error_kind(io::ErrorKind::BrokenPipe),
+            Some(ErrorClass::Connection)
+        );
+        assert_eq!(
+            ErrorClass::from_io_error_kind(io::ErrorKind::TimedOut),
+            Some(ErrorClass::Timeout)
+        );
+    }
+}
+
+//
+#[cfg(test)]
+pub(crate) mod loopback {
+    use std::{net::SocketAddr, time::Duration};
+
+    use tokio::{
+        io::{AsyncReadExt as _, AsyncWriteExt as _},
+        net::TcpListener,
+    };
+
+    /// a port nothing listens on
+    pub(crate) async fn refused_addr() -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        listener.local_addr().unwrap()
+    }
+
+    /// reads one request, writes response and closes, or hangs when response is None
+    pub(crate) async fn serve_once(response: Option<&'static [u8]>) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                match stream.read(&mut buf).await {
+                    Ok(0) | Err(_) => return,
+                    Ok(n) => request.extend_from_slice(&buf[..n]),
+                }
+            }
+
+            match response {
+                Some(response) => {
+                    let _ = stream.write_all(response).await;
+                    let _ = stream.shutdown().await;
+                }
+                None => tokio::time::sleep(Duration::from_secs(10)).await,
+            }
+        });
+
+        addr
+    }
+
+    pub(crate) const NOT_HTTP: &[u8] = b"NOT HTTP\r\n\r\n";
+    pub(crate) const TRUNCATED_BODY: &[u8] = b"HTTP/1.1 200 OK\r\ncontent-length: 100\r\n\r\nshort";
+    pub(crate) const SERVICE_UNAVAILABLE: &[u8] =
+        b"HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\n\r\n";
+    pub(crate) const NOT_FOUND: &[u8] = b"HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\n\r\n";
+}
@@ -0,0 +1,188 @@
+//! Retries transient [`std::io::Error`]s, by `ErrorKind` or by raw OS error code.
+
+use std::io::{Error, ErrorKind};
+
+use crate::retry_predicate::RetryPredicate;
+
+/// The default set, connection-level failures and interruptions.
+pub const TRANSIENT_KINDS: &[ErrorKind] = &[
+    ErrorKind::ConnectionRefused,
+    ErrorKind::ConnectionReset,
+    ErrorKind::ConnectionAborted,
+    ErrorKind::NotConnected,
+    ErrorKind::HostUnreachable,
+    ErrorKind::NetworkUnreachable,
+    ErrorKind::NetworkDown,
+    ErrorKind::BrokenPipe,
+    ErrorKind::TimedOut,
+    ErrorKind::Interrupted,
+    ErrorKind::WouldBlock,
+    ErrorKind::ResourceBusy,
+    ErrorKind::UnexpectedEof,
+];
+
+//
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Predicate {
+    kinds: Vec<ErrorKind>,
+    raw_os_errors: Vec<i32>,
+}
+
+impl Default for Predicate {
+    fn default() -> Self {
+        Self {
+            kinds: TRANSIENT_KINDS.to_vec(),
+            raw_os_errors: Vec::new(),
+        }
+    }
+}
+
+impl Predicate {
+    /// with [`TRANSIENT_KINDS`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// matches nothing until kinds or raw OS errors are added
+    pub fn empty() -> Self {
+        Self {
+            kinds: Vec::new(),
+            raw_os_errors: Vec::new(),
+        }
+    }
+
+    pub fn with_kind(mut self, kind: ErrorKind) -> Self {
+        if !self.kinds.contains(&kind) {
+            self.kinds.push(kind);
+        }
+        self
+    }
+
+    pub fn without_kind(mut self, kind: ErrorKind) -> Self {
+        self.kinds.retain(|x| *x != kind);
+        self
+    }
+
+    /// matched before the kind, e.g. `libc::EAGAIN`
+    pub fn with_raw_os_error(mut self, code: i32) -> Self {
+        if !self.raw_os_errors.contains(&code) {
+            self.raw_os_errors.push(code);
+        }
+        self
+    }
+
+    pub fn without_raw_os_error(mut self, code: i32) -> Self {
+        self.raw_os_errors.retain(|x| *x != code);
+        self
+    }
+
+    pub fn kinds(&self) -> &[ErrorKind] {
+        &self.kinds
+    }
+
+    pub fn raw_os_errors(&self) -> &[i32] {
+        &self.raw_os_errors
+    }
+}
+
+//
+impl RetryPredicate<Error> for Predicate {
+    fn test(&self, params: &Error) -> bool {
+        if let Some(code) = params.raw_os_error()
+            && self.raw_os_errors.contains(&code)
+        {
+            return true;
+        }
+
+        RetryPredicate::<ErrorKind>::test(self, &params.kind())
+    }
+
+    fn name(&self) -> &str {
+        "IoError"
+    }
+}
+
+impl RetryPredicate<ErrorKind> for Predicate {
+    fn test(&self, params: &ErrorKind) -> bool {
+        self.kinds.contains(params)
+    }
+
+    fn name(&self) -> &str {
+        "IoError"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_impl_retry_predicate() {
+        let predicate = Predicate::new();
+
+        for kind in [
+            ErrorKind::ConnectionReset,
+            ErrorKind::ConnectionAborted,
+            ErrorKind::TimedOut,
+            ErrorKind::Interrupted,
+            ErrorKind::WouldBlock,
+            ErrorKind::BrokenPipe,
+        ] {
+            assert!(
+                RetryPredicate::test(&predicate, &Error::from(kind)),
+                "{kind}"
+            );
+        }
+        for kind in [
+            ErrorKind::NotFound,
+            ErrorKind::PermissionDenied,
+            ErrorKind::InvalidInput,
+            ErrorKind::InvalidData,
+            ErrorKind::Other,
+        ] {
+            assert!(
+                !RetryPredicate::test(&predicate, &Error::from(kind)),
+                "{kind}"
+            );
+        }
+        assert!(!RetryPredicate::test(&predicate, &Error::other("custom")));
+
+        assert_eq!(RetryPredicate::<Error>::name(&predicate), "IoError");
+    }
+
+    #[test]
+    fn test_builder() {
+        let predicate = Predicate::new()
+            .without_kind(ErrorKind::UnexpectedEof)
+            .with_kind(ErrorKind::NotFound)
+            .with_kind(ErrorKind::NotFound);
+        assert!(RetryPredicate::test(&predicate, &ErrorKind::NotFound));
+        assert!(!RetryPredicate::test(&predicate, &ErrorKind::UnexpectedEof));
+        assert_eq!(predicate.kinds().len(), TRANSIENT_KINDS.len());
+
+        let predicate = Predicate::empty();
+        assert!(!RetryPredicate::test(&predicate, &ErrorKind::TimedOut));
+        assert_eq!(Predicate::default(), Predicate::new());
+    }
+
+    #[test]
+    fn test_raw_os_error() {
+        // EACCES, outside the default kinds
+        let code = 13;
+        let err = Error::from_raw_os_error(code);
+        #[cfg(unix)]
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        assert!(!RetryPredicate::test(&Predicate::new(), &err));
+
+        let predicate = Predicate::empty().with_raw_os_error(code);
+        assert!(RetryPredicate::test(&predicate, &err));
+        assert!(!RetryPredicate::test(
+            &predicate,
+            &Error::from(ErrorKind::PermissionDenied)
+        ));
+        assert_eq!(predicate.raw_os_errors(), &[code]);
+
+        let predicate = predicate.without_raw_os_error(code);
+        assert!(!RetryPredicate::test(&predicate, &err));
+    }
+}
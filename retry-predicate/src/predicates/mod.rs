@@ -5,9 +5,46 @@ mod r#fn;
 #[cfg(feature = "alloc")]
 pub use r#fn::Predicate as FnPredicate;
 
+//
+#[cfg(feature = "std")]
+pub mod io_error;
+
+#[cfg(feature = "std")]
+pub use io_error::Predicate as IoErrorPredicate;
+
+//
+#[cfg(feature = "http")]
+pub mod http;
+
+#[cfg(feature = "http")]
+pub use self::http::{MethodAware as HttpMethodAwarePredicate, Predicate as HttpStatusPredicate};
+
+#[cfg(feature = "hyper")]
+pub mod http_client;
+
+#[cfg(feature = "hyper")]
+pub use http_client::{ErrorClass as HttpClientErrorClass, Predicate as HttpClientPredicate};
+
+//
+#[cfg(feature = "tonic")]
+pub mod grpc;
+
+#[cfg(feature = "tonic")]
+pub use grpc::Predicate as GrpcStatusPredicate;
+
+//
+pub mod errno;
+
+pub use errno::Predicate as ErrnoPredicate;
+
 //
 mod always;
 mod never;
 
 pub use always::Predicate as AlwaysPredicate;
 pub use never::Predicate as NeverPredicate;
+
+//
+pub mod combinators;
+
+pub use combinators::{AllOf, And, AnyOf, Not, Or, PredicateList, all_of, any_of};
@@ -11,6 +11,34 @@ pub trait RetryPredicate<Params> {
     }
 }
 
+//
+impl<P, Params> RetryPredicate<Params> for &P
+where
+    P: RetryPredicate<Params> + ?Sized,
+{
+    fn test(&self, params: &Params) -> bool {
+        (**self).test(params)
+    }
+
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P, Params> RetryPredicate<Params> for alloc::boxed::Box<P>
+where
+    P: RetryPredicate<Params> + ?Sized,
+{
+    fn test(&self, params: &Params) -> bool {
+        (**self).test(params)
+    }
+
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+}
+
 //
 impl<Params> fmt::Debug for dyn RetryPredicate<Params> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -0,0 +1,34 @@
+use crate::{
+    predicates::combinators::{And, Not, Or},
+    retry_predicate::RetryPredicate,
+};
+
+//
+pub trait RetryPredicateExt<Params>: RetryPredicate<Params> {
+    /// retries when both do, short-circuiting
+    fn and<P>(self, other: P) -> And<Self, P, Params>
+    where
+        Self: Sized,
+        P: RetryPredicate<Params>,
+    {
+        And::new(self, other)
+    }
+
+    /// retries when either does, short-circuiting
+    fn or<P>(self, other: P) -> Or<Self, P, Params>
+    where
+        Self: Sized,
+        P: RetryPredicate<Params>,
+    {
+        Or::new(self, other)
+    }
+
+    fn not(self) -> Not<Self, Params>
+    where
+        Self: Sized,
+    {
+        Not::new(self)
+    }
+}
+
+impl<P, Params> RetryPredicateExt<Params> for P where P: RetryPredicate<Params> + ?Sized {}